@@ -0,0 +1,428 @@
+use gloo_net::http::{Method, Request, RequestBuilder, Response};
+use serde::{de::DeserializeOwned, Serialize};
+use uuid::Uuid;
+use web_sys::{AbortSignal, Blob, FormData, RequestCredentials};
+
+use crate::{
+    api::error::ApiError,
+    dto::{
+        api_response::ResponseFormat,
+        auth::{
+            ChangeEmailRequest, ChangeEmailResponse, ChangePasswordRequest, ForgotPasswordRequest,
+            LoginRequest, LoginResponse, ReauthenticateRequest, ReauthenticateResponse,
+            ResendVerificationRequest, ResendVerificationResponse, ResetPasswordRequest,
+            SessionResponse, SignupRequest, SignupResponse, VerifyEmailRequest,
+            VerifyEmailResponse,
+        },
+        comment::{Comment, CreateCommentRequest, UpdateCommentRequest},
+        dropdown::{CountryData, IsoCountry, IsoLanguage, Subdivision},
+        image::UploadedImage,
+        post::{EditablePost, Post, PostPage, SavePostRequest, SetPublishedRequest, TagCount},
+        profile::ProfileDocument,
+        project::Project,
+        search::SearchPage,
+        user::{UpdateProfileRequest, UserProfile},
+    },
+    utils::feed::FeedFormat,
+};
+
+/// Thin wrapper around `gloo_net` that owns the backend base URL and API key,
+/// attaches the default headers and credentials mode to every request, and
+/// unwraps `ResponseFormat<T>` into a typed `Result`.
+///
+/// Provided through context by `App`; grab it with `use_context::<ApiClient>()`.
+#[derive(Clone, Debug)]
+pub struct ApiClient {
+    base_url: String,
+    api_key: String,
+}
+
+impl ApiClient {
+    pub fn new(base_url: impl Into<String>, api_key: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            api_key: api_key.into(),
+        }
+    }
+
+    /// Builds a request against `path` (relative to the base URL) with the
+    /// API key attached and cookies included, so the session cookie is sent
+    /// and received on every call.
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        RequestBuilder::new(&format!("{}{}", self.base_url, path))
+            .method(method)
+            .credentials(RequestCredentials::Include)
+            .header("x-api-key", &self.api_key)
+    }
+
+    /// Sends the request and returns the raw response along with its body text.
+    async fn dispatch(
+        request: Result<Request, gloo_net::Error>,
+    ) -> Result<(Response, String), ApiError> {
+        let request = request.map_err(|err| ApiError::Request(err.to_string()))?;
+        let response = request
+            .send()
+            .await
+            .map_err(|err| ApiError::Network(err.to_string()))?;
+        let text = response
+            .text()
+            .await
+            .map_err(|err| ApiError::Network(err.to_string()))?;
+        Ok((response, text))
+    }
+
+    /// Turns a non-2xx response into the most precise `ApiError` we can:
+    /// a `Backend` error if the body is a failure envelope, `Http` otherwise.
+    fn failure(response: &Response, text: &str) -> ApiError {
+        match serde_json::from_str::<ResponseFormat<serde_json::Value>>(text) {
+            Ok(ResponseFormat::Failure { error, meta }) => ApiError::Backend {
+                status: response.status(),
+                error,
+                meta,
+            },
+            _ => ApiError::Http {
+                status: response.status(),
+                status_text: response.status_text(),
+            },
+        }
+    }
+
+    /// Sends the request and decodes the `ResponseFormat<T>` envelope.
+    async fn send<T>(request: Result<Request, gloo_net::Error>) -> Result<T, ApiError>
+    where
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        let (response, text) = Self::dispatch(request).await?;
+
+        if !response.ok() {
+            return Err(Self::failure(&response, &text));
+        }
+
+        match serde_json::from_str::<ResponseFormat<T>>(&text) {
+            Ok(ResponseFormat::Success { data, .. }) => Ok(data),
+            Ok(ResponseFormat::Failure { error, meta }) => Err(ApiError::Backend {
+                status: response.status(),
+                error,
+                meta,
+            }),
+            Err(err) => Err(ApiError::Decode(err.to_string())),
+        }
+    }
+
+    pub async fn get<T>(&self, path: &str) -> Result<T, ApiError>
+    where
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        Self::send(self.request(Method::GET, path).build()).await
+    }
+
+    pub async fn post<B, T>(&self, path: &str, body: &B) -> Result<T, ApiError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        Self::send(self.request(Method::POST, path).json(body)).await
+    }
+
+    pub async fn patch<B, T>(&self, path: &str, body: &B) -> Result<T, ApiError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        Self::send(self.request(Method::PATCH, path).json(body)).await
+    }
+
+    /// Sends a bodiless request and ignores the payload on success, for
+    /// endpoints such as logout whose response we do not care about.
+    async fn send_empty(&self, method: Method, path: &str) -> Result<(), ApiError> {
+        let (response, text) = Self::dispatch(self.request(method, path).build()).await?;
+
+        if response.ok() {
+            Ok(())
+        } else {
+            Err(Self::failure(&response, &text))
+        }
+    }
+
+    pub async fn post_empty(&self, path: &str) -> Result<(), ApiError> {
+        self.send_empty(Method::POST, path).await
+    }
+
+    pub async fn delete_empty(&self, path: &str) -> Result<(), ApiError> {
+        self.send_empty(Method::DELETE, path).await
+    }
+
+    /// Fetches `path` and returns its body as-is, for endpoints that do not
+    /// answer with a `ResponseFormat` envelope.
+    async fn get_text(&self, path: &str) -> Result<String, ApiError> {
+        let (response, text) = Self::dispatch(self.request(Method::GET, path).build()).await?;
+
+        if response.ok() {
+            Ok(text)
+        } else {
+            Err(Self::failure(&response, &text))
+        }
+    }
+
+    // --- auth ---
+
+    pub async fn login(&self, request: &LoginRequest) -> Result<LoginResponse, ApiError> {
+        self.post("/auth/login", request).await
+    }
+
+    pub async fn logout(&self) -> Result<(), ApiError> {
+        self.post_empty("/auth/logout").await
+    }
+
+    /// Resolves the current session from the HttpOnly cookie.
+    pub async fn session(&self) -> Result<SessionResponse, ApiError> {
+        self.get("/auth/me").await
+    }
+
+    pub async fn signup(&self, request: &SignupRequest) -> Result<SignupResponse, ApiError> {
+        self.post("/auth/signup", request).await
+    }
+
+    /// Consumes the token from a verification email link.
+    pub async fn verify_email(
+        &self,
+        request: &VerifyEmailRequest,
+    ) -> Result<VerifyEmailResponse, ApiError> {
+        self.post("/auth/verify-email", request).await
+    }
+
+    pub async fn resend_verification_email(
+        &self,
+        request: &ResendVerificationRequest,
+    ) -> Result<ResendVerificationResponse, ApiError> {
+        self.post("/auth/resend-verification-email", request).await
+    }
+
+    /// Confirms the current password, opening a short window for credential changes.
+    pub async fn reauthenticate(
+        &self,
+        request: &ReauthenticateRequest,
+    ) -> Result<ReauthenticateResponse, ApiError> {
+        self.post("/auth/reauthenticate", request).await
+    }
+
+    /// Requires a recent `reauthenticate`.
+    pub async fn change_password(&self, request: &ChangePasswordRequest) -> Result<(), ApiError> {
+        self.post::<_, serde_json::Value>("/auth/change-password", request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Requires a recent `reauthenticate`.
+    pub async fn change_email(
+        &self,
+        request: &ChangeEmailRequest,
+    ) -> Result<ChangeEmailResponse, ApiError> {
+        self.post("/auth/change-email", request).await
+    }
+
+    /// The backend answers the same way whether or not the account exists.
+    pub async fn forgot_password(&self, request: &ForgotPasswordRequest) -> Result<(), ApiError> {
+        self.post::<_, serde_json::Value>("/auth/forgot-password", request)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn reset_password(&self, request: &ResetPasswordRequest) -> Result<(), ApiError> {
+        self.post::<_, serde_json::Value>("/auth/reset-password", request)
+            .await
+            .map(|_| ())
+    }
+
+    // --- user ---
+
+    pub async fn profile(&self) -> Result<UserProfile, ApiError> {
+        self.get("/user/profile").await
+    }
+
+    pub async fn update_profile(
+        &self,
+        request: &UpdateProfileRequest,
+    ) -> Result<UserProfile, ApiError> {
+        self.patch("/user/profile", request).await
+    }
+
+    /// Everything the backend stores about the user, as free-form JSON.
+    pub async fn export_account(&self) -> Result<serde_json::Value, ApiError> {
+        self.get("/user/export").await
+    }
+
+    /// Permanently deletes the account and ends the session.
+    pub async fn delete_account(&self) -> Result<(), ApiError> {
+        self.delete_empty("/user/profile").await
+    }
+
+    // --- posts ---
+
+    /// Published posts, newest first. `page` is 1-based.
+    pub async fn posts(&self, page: u32, page_size: u32) -> Result<PostPage, ApiError> {
+        self.get(&format!("/posts?page={}&page_size={}", page, page_size))
+            .await
+    }
+
+    /// Published posts carrying `tag`, newest first. `page` is 1-based.
+    pub async fn posts_with_tag(
+        &self,
+        tag: &str,
+        page: u32,
+        page_size: u32,
+    ) -> Result<PostPage, ApiError> {
+        self.get(&format!(
+            "/posts?tag={}&page={}&page_size={}",
+            urlencoding::encode(tag),
+            page,
+            page_size
+        ))
+        .await
+    }
+
+    /// Every tag in use, with its number of published posts.
+    pub async fn tags(&self) -> Result<Vec<TagCount>, ApiError> {
+        self.get("/tags").await
+    }
+
+    pub async fn post_by_slug(&self, slug: &str) -> Result<Post, ApiError> {
+        self.get(&format!("/posts/{}", urlencoding::encode(slug)))
+            .await
+    }
+
+    /// The author's copy of a post, drafts included. Fails with 403 for
+    /// anyone but its author.
+    pub async fn editable_post(&self, slug: &str) -> Result<EditablePost, ApiError> {
+        self.get(&format!("/posts/{}/edit", urlencoding::encode(slug)))
+            .await
+    }
+
+    /// Creates an unpublished post.
+    pub async fn create_post(&self, request: &SavePostRequest) -> Result<EditablePost, ApiError> {
+        self.post("/posts", request).await
+    }
+
+    pub async fn update_post(
+        &self,
+        post_id: Uuid,
+        request: &SavePostRequest,
+    ) -> Result<EditablePost, ApiError> {
+        self.patch(&format!("/posts/{}", post_id), request).await
+    }
+
+    pub async fn set_post_published(
+        &self,
+        post_id: Uuid,
+        is_published: bool,
+    ) -> Result<EditablePost, ApiError> {
+        self.patch(
+            &format!("/posts/{}/published", post_id),
+            &SetPublishedRequest { is_published },
+        )
+        .await
+    }
+
+    // --- search ---
+
+    /// Full-text search over published posts. Aborting `abort` cancels the
+    /// request, e.g. once a newer query supersedes it.
+    pub async fn search_posts(
+        &self,
+        query: &str,
+        page: u32,
+        page_size: u32,
+        abort: Option<&AbortSignal>,
+    ) -> Result<SearchPage, ApiError> {
+        let path = format!(
+            "/posts/search?q={}&page={}&page_size={}",
+            urlencoding::encode(query),
+            page,
+            page_size
+        );
+        Self::send(self.request(Method::GET, &path).abort_signal(abort).build()).await
+    }
+
+    // --- comments ---
+
+    /// Every comment on a post, replies included, as a flat list.
+    pub async fn comments(&self, post_id: Uuid) -> Result<Vec<Comment>, ApiError> {
+        self.get(&format!("/posts/{}/comments", post_id)).await
+    }
+
+    pub async fn create_comment(
+        &self,
+        post_id: Uuid,
+        request: &CreateCommentRequest,
+    ) -> Result<Comment, ApiError> {
+        self.post(&format!("/posts/{}/comments", post_id), request)
+            .await
+    }
+
+    pub async fn update_comment(
+        &self,
+        comment_id: Uuid,
+        request: &UpdateCommentRequest,
+    ) -> Result<Comment, ApiError> {
+        self.patch(&format!("/comments/{}", comment_id), request)
+            .await
+    }
+
+    pub async fn delete_comment(&self, comment_id: Uuid) -> Result<(), ApiError> {
+        self.delete_empty(&format!("/comments/{}", comment_id))
+            .await
+    }
+
+    // --- feeds ---
+
+    /// The raw feed document, for previewing what feed readers will get.
+    pub async fn feed(&self, format: FeedFormat) -> Result<String, ApiError> {
+        self.get_text(format.path()).await
+    }
+
+    // --- images ---
+
+    /// Uploads an image as `multipart/form-data` under the `image` field.
+    /// The browser fills in the multipart boundary.
+    pub async fn upload_image(
+        &self,
+        image: &Blob,
+        filename: &str,
+    ) -> Result<UploadedImage, ApiError> {
+        let form = FormData::new().map_err(|err| ApiError::Request(format!("{:?}", err)))?;
+        form.append_with_blob_and_filename("image", image, filename)
+            .map_err(|err| ApiError::Request(format!("{:?}", err)))?;
+        Self::send(self.request(Method::POST, "/images").body(form)).await
+    }
+
+    // --- about ---
+
+    /// The author's résumé shown on the About page.
+    pub async fn about_profile(&self) -> Result<ProfileDocument, ApiError> {
+        self.get("/about").await
+    }
+
+    // --- projects ---
+
+    /// Every portfolio project, in no particular order.
+    pub async fn projects(&self) -> Result<Vec<Project>, ApiError> {
+        self.get("/projects").await
+    }
+
+    // --- dropdowns ---
+
+    pub async fn countries(&self) -> Result<Vec<IsoCountry>, ApiError> {
+        self.get::<CountryData>("/dropdown/country")
+            .await
+            .map(|data| data.countries)
+    }
+
+    pub async fn languages(&self) -> Result<Vec<IsoLanguage>, ApiError> {
+        self.get("/dropdown/language").await
+    }
+
+    pub async fn subdivisions(&self, country_code: i32) -> Result<Vec<Subdivision>, ApiError> {
+        self.get(&format!("/dropdown/country/{}/subdivision", country_code))
+            .await
+    }
+}
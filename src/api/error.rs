@@ -0,0 +1,93 @@
+use std::fmt;
+
+use crate::dto::api_response::{ErrorBody, FieldError, Meta};
+
+/// Backend `error_code` values the UI reacts to specifically.
+pub mod error_codes {
+    pub const TOKEN_EXPIRED: &str = "TOKEN_EXPIRED";
+    pub const TOKEN_INVALID: &str = "TOKEN_INVALID";
+    pub const EMAIL_ALREADY_VERIFIED: &str = "EMAIL_ALREADY_VERIFIED";
+    pub const REAUTH_REQUIRED: &str = "REAUTH_REQUIRED";
+}
+
+/// Everything that can go wrong when talking to the backend through `ApiClient`.
+#[derive(Clone, Debug)]
+pub enum ApiError {
+    /// The request could not be built (body serialization, headers, ...).
+    Request(String),
+    /// The request never got a response (CORS, offline, DNS, ...).
+    Network(String),
+    /// The backend answered with a non-2xx status and no readable error envelope.
+    Http { status: u16, status_text: String },
+    /// The response body could not be deserialized as JSON.
+    Decode(String),
+    /// The backend answered with `success: false` and an `ErrorBody`.
+    Backend {
+        status: u16,
+        error: ErrorBody,
+        meta: Meta,
+    },
+}
+
+impl ApiError {
+    /// The backend's error code, if this is a business error.
+    pub fn error_code(&self) -> Option<&str> {
+        match self {
+            ApiError::Backend { error, .. } => Some(&error.error_code),
+            _ => None,
+        }
+    }
+
+    /// Field-level validation errors reported by the backend, if any.
+    pub fn field_errors(&self) -> &[FieldError] {
+        match self {
+            ApiError::Backend { error, .. } => &error.field_errors,
+            _ => &[],
+        }
+    }
+
+    /// The HTTP status code, if a response was received at all.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ApiError::Http { status, .. } | ApiError::Backend { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// A message suitable for showing to the user as-is.
+    pub fn user_message(&self) -> String {
+        match self {
+            ApiError::Request(_) => "The request could not be sent.".to_owned(),
+            ApiError::Network(_) => "Could not reach the server. Please try again.".to_owned(),
+            ApiError::Http { status, .. } if *status >= 500 => {
+                "The server ran into a problem. Please try again later.".to_owned()
+            }
+            ApiError::Http { status, .. } => format!("Request failed ({}).", status),
+            ApiError::Decode(_) => "The server sent an unexpected response.".to_owned(),
+            ApiError::Backend { error, .. } => error.message.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Request(err) => write!(f, "could not build request: {}", err),
+            ApiError::Network(err) => write!(f, "network error: {}", err),
+            ApiError::Http {
+                status,
+                status_text,
+            } => write!(f, "backend responded with {} {}", status, status_text),
+            ApiError::Decode(err) => write!(f, "could not decode response: {}", err),
+            ApiError::Backend { status, error, .. } => {
+                write!(f, "[{}] {}: {}", status, error.error_code, error.message)?;
+                if let Some(request_id) = &error.request_id {
+                    write!(f, " (request id {})", request_id)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
@@ -0,0 +1,26 @@
+use leptos::{logging::log, prelude::*};
+
+use crate::{api::client::ApiClient, GlobalAppState};
+
+/// Asks the backend who the session cookie belongs to and rebuilds the
+/// in-memory auth state from the answer. Any failure leaves the user signed out.
+pub async fn revive_session(api: &ApiClient, set_global_state: WriteSignal<GlobalAppState>) {
+    match api.session().await {
+        Ok(session) => {
+            set_global_state.update(|state| state.sign_in(session.user_id, session.user_email));
+        }
+        Err(err) => {
+            // A 401 simply means there is no live session; anything else is worth logging.
+            if err.status() != Some(401) {
+                log!("Session revive failed: {}", err);
+            }
+            set_global_state.update(|state| state.sign_out());
+        }
+    }
+}
+
+/// Forgets the logged-in user locally once the backend session is gone
+/// (logout, account deletion). Callers navigate home afterwards.
+pub fn end_session(set_global_state: WriteSignal<GlobalAppState>) {
+    set_global_state.update(|state| state.sign_out());
+}
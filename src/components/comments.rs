@@ -0,0 +1,523 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Local, Utc};
+use leptos::{logging::log, prelude::*, task::spawn_local};
+use leptos_router::hooks::use_location;
+use urlencoding::encode;
+use uuid::Uuid;
+
+use crate::{
+    api::client::ApiClient,
+    components::field_error::FieldErrorMessage,
+    dto::comment::{Comment, CreateCommentRequest, UpdateCommentRequest},
+    utils::{
+        markdown::render_markdown_lite,
+        time::{format_relative, use_clock},
+        validation::{apply_field_errors, Field, Rule},
+    },
+    GlobalAppState,
+};
+
+/// Replies nest visually up to this depth; deeper ones line up with it so
+/// long back-and-forths stay readable.
+const MAX_DEPTH: usize = 4;
+
+fn comment_rules() -> Vec<Rule> {
+    vec![Rule::Required, Rule::MaxLength(5000)]
+}
+
+/// The loaded comments of one post, shared with every node of the thread.
+#[derive(Clone, Copy)]
+struct Thread {
+    post_id: Uuid,
+    comments: RwSignal<Vec<Comment>>,
+    now: ReadSignal<DateTime<Utc>>,
+}
+
+impl Thread {
+    /// Direct replies to `parent`, oldest first. Top-level comments also take
+    /// in replies whose parent is missing from the list.
+    fn children(self, parent: Option<Uuid>) -> Vec<Comment> {
+        self.comments.with(|comments| {
+            let known: HashSet<Uuid> = comments.iter().map(|c| c.comment_id).collect();
+            let mut children: Vec<Comment> = comments
+                .iter()
+                .filter(|comment| match parent {
+                    Some(parent) => comment.parent_id == Some(parent),
+                    None => comment.parent_id.is_none_or(|id| !known.contains(&id)),
+                })
+                .cloned()
+                .collect();
+            children.sort_by_key(|comment| comment.created_at);
+            children
+        })
+    }
+
+    /// What is left of a deleted comment: a tombstone if it has replies to
+    /// keep in context, `Some(None)` if it can go entirely. `None` once the
+    /// thread itself is gone, e.g. after leaving the post mid-request.
+    fn tombstone(self, comment_id: Uuid) -> Option<Option<Comment>> {
+        self.comments.try_with_untracked(|comments| {
+            let has_replies = comments
+                .iter()
+                .any(|comment| comment.parent_id == Some(comment_id));
+            if !has_replies {
+                return None;
+            }
+            let mut tombstone = comments
+                .iter()
+                .find(|comment| comment.comment_id == comment_id)
+                .cloned()?;
+            tombstone.is_deleted = true;
+            tombstone.body.clear();
+            Some(tombstone)
+        })
+    }
+
+    /// Replaces the comment with the same id, or appends it.
+    fn upsert(self, comment: Comment) {
+        self.comments.update(|comments| {
+            match comments
+                .iter_mut()
+                .find(|existing| existing.comment_id == comment.comment_id)
+            {
+                Some(existing) => *existing = comment,
+                None => comments.push(comment),
+            }
+        });
+    }
+
+    fn remove(self, comment_id: Uuid) {
+        self.comments
+            .update(|comments| comments.retain(|comment| comment.comment_id != comment_id));
+    }
+}
+
+/// Comments under a post, with replies nested below their parents.
+#[component]
+pub fn CommentThread(post_id: Uuid) -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let global_state =
+        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
+    let location = use_location();
+
+    let thread = Thread {
+        post_id,
+        comments: RwSignal::new(Vec::new()),
+        now: use_clock(),
+    };
+    provide_context(thread);
+
+    // `None` while loading.
+    let (load_result, set_load_result) = signal(None::<Result<(), String>>);
+    spawn_local({
+        let api = api.clone();
+        async move {
+            match api.comments(post_id).await {
+                Ok(comments) => {
+                    thread.comments.set(comments);
+                    set_load_result.set(Some(Ok(())));
+                }
+                Err(err) => {
+                    log!("Loading comments failed: {}", err);
+                    set_load_result.set(Some(Err(err.user_message())));
+                }
+            }
+        }
+    });
+
+    let draft = Field::new(comment_rules());
+    let (is_busy, set_busy) = signal(false);
+    let (error, set_error) = signal(None::<String>);
+    let submit = Callback::new(move |()| {
+        let request = CreateCommentRequest {
+            parent_id: None,
+            body: draft.value.get_untracked(),
+        };
+        let api = api.clone();
+        set_busy.set(true);
+        set_error.set(None);
+        spawn_local(async move {
+            match api.create_comment(post_id, &request).await {
+                Ok(comment) => {
+                    thread.upsert(comment);
+                    draft.reset(String::new());
+                }
+                Err(err) => {
+                    log!("Posting comment failed: {}", err);
+                    apply_field_errors(&[("body", draft)], err.field_errors());
+                    set_error.set(Some(err.user_message()));
+                }
+            }
+            set_busy.set(false);
+        });
+    });
+
+    let count = move || {
+        thread
+            .comments
+            .with(|comments| comments.iter().filter(|c| !c.is_deleted).count())
+    };
+    let login_href = move || {
+        let here = format!("{}#post-comments", location.pathname.get());
+        format!("/account/login?next={}", encode(&here))
+    };
+
+    view! {
+        <style>{include_str!("./comments.css")}</style>
+        <section class="comments" id="post-comments">
+            <h2>{move || format!("Comments ({})", count())}</h2>
+            {move || {
+                let (checked, logged_in) = global_state
+                    .with(|state| (state.is_session_checked, state.is_logged_in));
+                if !checked {
+                    ().into_any()
+                } else if logged_in {
+                    view! {
+                        <CommentComposer
+                            field=draft
+                            busy=is_busy
+                            error=error
+                            submit_label="Comment"
+                            on_submit=submit
+                        />
+                    }
+                        .into_any()
+                } else {
+                    view! {
+                        <p class="comment-login-prompt">
+                            <a href=login_href>"Log in"</a>
+                            " to join the discussion."
+                        </p>
+                    }
+                        .into_any()
+                }
+            }}
+            {move || match load_result.get() {
+                None => view! { <p class="comment-message">"Loading comments…"</p> }.into_any(),
+                Some(Err(message)) => {
+                    view! { <p class="comment-message">{message}</p> }.into_any()
+                }
+                Some(Ok(())) if count() == 0 => {
+                    view! { <p class="comment-message">"No comments yet."</p> }.into_any()
+                }
+                Some(Ok(())) => ().into_any(),
+            }}
+            <CommentList parent=None depth=0 />
+        </section>
+    }
+}
+
+#[component]
+fn CommentList(parent: Option<Uuid>, depth: usize) -> AnyView {
+    let thread = use_context::<Thread>().expect("comment thread not provided");
+    let indented = depth > 0 && depth <= MAX_DEPTH;
+
+    view! {
+        <ul class="comment-list" class:nested=indented>
+            <For
+                each=move || thread.children(parent)
+                // Nodes follow edits and deletions themselves, so open composers survive.
+                key=|comment| comment.comment_id
+                let:comment
+            >
+                <CommentNode comment=comment depth=depth />
+            </For>
+        </ul>
+    }
+    .into_any()
+}
+
+#[component]
+fn CommentNode(comment: Comment, depth: usize) -> AnyView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let global_state =
+        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
+    let thread = use_context::<Thread>().expect("comment thread not provided");
+
+    let comment_id = comment.comment_id;
+    let author_id = comment.author_id;
+    let created_at = comment.created_at;
+    // The latest version in the thread; the initial one once it has left it.
+    let comment = Memo::new(move |_| {
+        thread
+            .comments
+            .with(|comments| {
+                comments
+                    .iter()
+                    .find(|current| current.comment_id == comment_id)
+                    .cloned()
+            })
+            .unwrap_or_else(|| comment.clone())
+    });
+    let is_deleted = move || comment.with(|comment| comment.is_deleted);
+    let logged_in = move || global_state.with(|state| state.is_logged_in);
+    let is_own = move || global_state.with(|state| state.user_id == Some(author_id));
+
+    let replying = RwSignal::new(false);
+    let editing = RwSignal::new(false);
+    let reply = Field::new(comment_rules());
+    let edit = Field::new(comment_rules());
+    let (is_busy, set_busy) = signal(false);
+    let (error, set_error) = signal(None::<String>);
+
+    let send_reply = Callback::new({
+        let api = api.clone();
+        move |()| {
+            let request = CreateCommentRequest {
+                parent_id: Some(comment_id),
+                body: reply.value.get_untracked(),
+            };
+            let api = api.clone();
+            set_busy.set(true);
+            set_error.set(None);
+            spawn_local(async move {
+                match api.create_comment(thread.post_id, &request).await {
+                    Ok(created) => {
+                        thread.upsert(created);
+                        reply.reset(String::new());
+                        replying.set(false);
+                    }
+                    Err(err) => {
+                        log!("Posting reply failed: {}", err);
+                        apply_field_errors(&[("body", reply)], err.field_errors());
+                        set_error.set(Some(err.user_message()));
+                    }
+                }
+                set_busy.set(false);
+            });
+        }
+    });
+
+    let save_edit = Callback::new({
+        let api = api.clone();
+        move |()| {
+            let request = UpdateCommentRequest {
+                body: edit.value.get_untracked(),
+            };
+            let api = api.clone();
+            set_busy.set(true);
+            set_error.set(None);
+            spawn_local(async move {
+                match api.update_comment(comment_id, &request).await {
+                    Ok(updated) => {
+                        thread.upsert(updated);
+                        editing.set(false);
+                    }
+                    Err(err) => {
+                        log!("Editing comment failed: {}", err);
+                        apply_field_errors(&[("body", edit)], err.field_errors());
+                        set_error.set(Some(err.user_message()));
+                    }
+                }
+                set_busy.set(false);
+            });
+        }
+    });
+
+    let delete = Callback::new(move |()| {
+        let confirmed = window()
+            .confirm_with_message("Delete this comment?")
+            .unwrap_or(false);
+        if !confirmed {
+            return;
+        }
+        let api = api.clone();
+        let original = comment_id;
+        set_busy.set(true);
+        spawn_local(async move {
+            match api
+                .delete_comment(original)
+                .await
+                .map(|()| thread.tombstone(original))
+            {
+                Ok(Some(Some(tombstone))) => {
+                    thread.upsert(tombstone);
+                    replying.set(false);
+                }
+                Ok(Some(None)) => thread.remove(original),
+                Ok(None) => {}
+                Err(err) => {
+                    log!("Deleting comment failed: {}", err);
+                    set_error.set(Some(err.user_message()));
+                }
+            }
+            set_busy.set(false);
+        });
+    });
+
+    let timestamp = move || format_relative(created_at, thread.now.get());
+    let full_timestamp = created_at
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string();
+    let rendered = Memo::new(move |_| comment.with(|comment| render_markdown_lite(&comment.body)));
+    let reply_depth = (depth + 1).min(MAX_DEPTH + 1);
+
+    view! {
+        <li class="comment" id=format!("comment-{}", comment_id)>
+            <div class="comment-meta">
+                <span class="comment-author">
+                    {move || {
+                        if is_deleted() {
+                            String::from("[deleted]")
+                        } else {
+                            comment.with(|comment| comment.author_name.clone())
+                        }
+                    }}
+                </span>
+                " · "
+                <time datetime=created_at.to_rfc3339() title=full_timestamp>
+                    {timestamp}
+                </time>
+                {move || {
+                    comment
+                        .with(|comment| comment.edited_at.is_some() && !comment.is_deleted)
+                        .then_some(" · edited")
+                }}
+            </div>
+            <Show
+                when=move || editing.get()
+                fallback=move || {
+                    move || {
+                        if is_deleted() {
+                            view! { <p class="comment-body deleted">"This comment was deleted."</p> }
+                                .into_any()
+                        } else {
+                            view! { <div class="comment-body" inner_html=rendered></div> }
+                                .into_any()
+                        }
+                    }
+                }
+            >
+                <CommentComposer
+                    field=edit
+                    busy=is_busy
+                    error=error
+                    submit_label="Save"
+                    on_submit=save_edit
+                    on_cancel=Callback::new(move |()| editing.set(false))
+                />
+            </Show>
+            <Show when=move || !is_deleted() && !editing.get() && logged_in()>
+                <div class="comment-actions">
+                    <button type="button" on:click=move |_| replying.update(|open| *open = !*open)>
+                        "Reply"
+                    </button>
+                    <Show when=is_own>
+                        <button
+                            type="button"
+                            on:click=move |_| {
+                                edit.reset(comment.with_untracked(|comment| comment.body.clone()));
+                                editing.set(true);
+                            }
+                        >
+                            "Edit"
+                        </button>
+                        <button type="button" disabled=is_busy on:click=move |_| delete.run(())>
+                            "Delete"
+                        </button>
+                    </Show>
+                </div>
+            </Show>
+            <Show when=move || !editing.get() && !replying.get()>
+                {move || error.get().map(|msg| view! { <p class="form-error">{msg}</p> })}
+            </Show>
+            <Show when=move || replying.get()>
+                <CommentComposer
+                    field=reply
+                    busy=is_busy
+                    error=error
+                    submit_label="Reply"
+                    on_submit=send_reply
+                    on_cancel=Callback::new(move |()| replying.set(false))
+                />
+            </Show>
+            <CommentList parent=Some(comment_id) depth=reply_depth />
+        </li>
+    }
+    .into_any()
+}
+
+/// Textarea with a Write/Preview toggle for Markdown-lite comments.
+#[component]
+fn CommentComposer(
+    field: Field,
+    #[prop(into)] busy: Signal<bool>,
+    #[prop(into)] error: Signal<Option<String>>,
+    submit_label: &'static str,
+    on_submit: Callback<()>,
+    #[prop(optional)] on_cancel: Option<Callback<()>>,
+) -> impl IntoView {
+    let previewing = RwSignal::new(false);
+
+    let on_form_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if busy.get_untracked() {
+            return;
+        }
+        if !field.is_valid() {
+            field.touch();
+            return;
+        }
+        previewing.set(false);
+        on_submit.run(());
+    };
+
+    view! {
+        <form class="comment-composer" on:submit=on_form_submit>
+            <div class="composer-tabs">
+                <button
+                    type="button"
+                    class:active=move || !previewing.get()
+                    on:click=move |_| previewing.set(false)
+                >
+                    "Write"
+                </button>
+                <button
+                    type="button"
+                    class:active=move || previewing.get()
+                    on:click=move |_| previewing.set(true)
+                >
+                    "Preview"
+                </button>
+            </div>
+            <Show
+                when=move || previewing.get()
+                fallback=move || {
+                    view! {
+                        <textarea
+                            rows="4"
+                            placeholder="Write a comment…"
+                            prop:value=move || field.value.get()
+                            on:input=move |ev| field.set(event_target_value(&ev))
+                        ></textarea>
+                    }
+                }
+            >
+                <div
+                    class="comment-body composer-preview"
+                    inner_html=move || field.value.with(|value| render_markdown_lite(value))
+                ></div>
+            </Show>
+            <FieldErrorMessage field=field />
+            <p class="composer-hint">
+                "Supports **bold**, *italic*, `code`, [links](https://…), lists and > quotes."
+            </p>
+            {move || error.get().map(|msg| view! { <p class="form-error">{msg}</p> })}
+            <div class="composer-actions">
+                {on_cancel
+                    .map(|cancel| {
+                        view! {
+                            <button type="button" on:click=move |_| cancel.run(())>
+                                "Cancel"
+                            </button>
+                        }
+                    })}
+                <button type="submit" class="primary" disabled=busy>
+                    {submit_label}
+                </button>
+            </div>
+        </form>
+    }
+}
@@ -0,0 +1,167 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+
+use crate::utils::head::{
+    remove_head_elements, set_meta, upsert_head_element, SITE_DESCRIPTION, SITE_TITLE,
+};
+
+/// What a page puts in the document head. Anything left `None` falls back
+/// to the site-wide defaults.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PageMeta {
+    /// Shown before the site name in the tab title and used for link previews.
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Path of the page's preferred URL. Defaults to the current path
+    /// without its query string.
+    pub canonical_path: Option<String>,
+    /// Preview image for shared links; a relative URL resolves against the origin.
+    pub image: Option<String>,
+    /// Set on posts, turning the page into an Open Graph `article`.
+    pub article: Option<ArticleMeta>,
+}
+
+impl PageMeta {
+    /// Site defaults apart from the title; enough for most pages.
+    pub fn titled(title: impl Into<String>) -> Self {
+        Self {
+            title: Some(title.into()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArticleMeta {
+    pub published_at: DateTime<Utc>,
+    pub modified_at: Option<DateTime<Utc>>,
+    pub tags: Vec<String>,
+}
+
+/// The meta of the page that called `use_head` last, tagged with that
+/// call's id so an unmounting page cannot clear its successor's.
+#[derive(Clone, Copy)]
+struct HeadState(RwSignal<Option<(u64, PageMeta)>>);
+
+static NEXT_HEAD_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Makes `use_head` available below. Called once by `Shell`.
+pub fn provide_head() {
+    provide_context(HeadState(RwSignal::new(None)));
+}
+
+/// Sets the document head from `meta` for as long as the calling page is
+/// mounted. `meta` is tracked, so a page can fill it in once its data loads;
+/// the defaults come back when the page goes away.
+pub fn use_head(meta: impl Fn() -> PageMeta + Send + Sync + 'static) {
+    let head = use_context::<HeadState>().expect("head state not provided");
+    let id = NEXT_HEAD_ID.fetch_add(1, Ordering::Relaxed);
+
+    Effect::new(move |_| head.0.set(Some((id, meta()))));
+    on_cleanup(move || {
+        let is_current = head
+            .0
+            .with_untracked(|active| active.as_ref().is_some_and(|(owner, _)| *owner == id));
+        if is_current {
+            head.0.set(None);
+        }
+    });
+}
+
+/// Keeps the document head in sync with the current page's `PageMeta` and
+/// the current path. Renders nothing; mount it once inside the router.
+#[component]
+pub fn DocumentHead() -> impl IntoView {
+    let head = use_context::<HeadState>().expect("head state not provided");
+    let location = use_location();
+
+    Effect::new(move |_| {
+        let pathname = location.pathname.get();
+        let meta = head
+            .0
+            .with(|active| active.as_ref().map(|(_, meta)| meta.clone()))
+            .unwrap_or_default();
+        apply(&meta, &pathname);
+    });
+}
+
+fn apply(meta: &PageMeta, pathname: &str) {
+    let origin = window().location().origin().unwrap_or_default();
+    let absolute = |url: &str| {
+        if url.starts_with('/') {
+            format!("{}{}", origin, url)
+        } else {
+            url.to_owned()
+        }
+    };
+
+    let title = meta.title.as_deref().unwrap_or(SITE_TITLE);
+    document().set_title(&match &meta.title {
+        Some(title) => format!("{} · {}", title, SITE_TITLE),
+        None => SITE_TITLE.to_owned(),
+    });
+    let description = meta.description.as_deref().unwrap_or(SITE_DESCRIPTION);
+    let url = absolute(meta.canonical_path.as_deref().unwrap_or(pathname));
+    let image = meta.image.as_deref().map(absolute);
+
+    upsert_head_element("link", "canonical", &[("rel", "canonical"), ("href", &url)]);
+    set_meta("name", "description", Some(description));
+
+    set_meta("property", "og:site_name", Some(SITE_TITLE));
+    set_meta("property", "og:title", Some(title));
+    set_meta("property", "og:description", Some(description));
+    set_meta("property", "og:url", Some(&url));
+    set_meta("property", "og:image", image.as_deref());
+    set_meta(
+        "property",
+        "og:type",
+        Some(if meta.article.is_some() {
+            "article"
+        } else {
+            "website"
+        }),
+    );
+
+    set_meta(
+        "name",
+        "twitter:card",
+        Some(if image.is_some() {
+            "summary_large_image"
+        } else {
+            "summary"
+        }),
+    );
+    set_meta("name", "twitter:title", Some(title));
+    set_meta("name", "twitter:description", Some(description));
+    set_meta("name", "twitter:image", image.as_deref());
+
+    let timestamp = |at: DateTime<Utc>| at.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let article = meta.article.as_ref();
+    set_meta(
+        "property",
+        "article:published_time",
+        article
+            .map(|article| timestamp(article.published_at))
+            .as_deref(),
+    );
+    set_meta(
+        "property",
+        "article:modified_time",
+        article
+            .and_then(|article| article.modified_at)
+            .map(timestamp)
+            .as_deref(),
+    );
+    // A tag per element; the count changes from post to post.
+    remove_head_elements("meta:article:tag");
+    for (index, tag) in article.iter().flat_map(|article| &article.tags).enumerate() {
+        upsert_head_element(
+            "meta",
+            &format!("meta:article:tag:{}", index),
+            &[("property", "article:tag"), ("content", tag)],
+        );
+    }
+}
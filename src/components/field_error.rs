@@ -0,0 +1,13 @@
+use leptos::prelude::*;
+
+use crate::utils::validation::Field;
+
+/// Inline error message rendered under a form input.
+#[component]
+pub fn FieldErrorMessage(field: Field) -> impl IntoView {
+    move || {
+        field
+            .visible_error()
+            .map(|message| view! { <p class="field-error">{message}</p> })
+    }
+}
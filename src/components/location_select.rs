@@ -0,0 +1,185 @@
+use leptos::{logging::log, prelude::*, task::spawn_local};
+
+use crate::{
+    api::client::ApiClient,
+    components::field_error::FieldErrorMessage,
+    dto::dropdown::{IsoCountry, IsoLanguage, Subdivision},
+    utils::validation::Field,
+};
+
+/// Country select with a dependent subdivision select.
+///
+/// The subdivision list follows `country`, whether it is changed by the user
+/// or set programmatically (e.g. when a form is prefilled). Picking a new
+/// country clears `subdivision`.
+#[component]
+pub fn CountrySubdivisionSelect(
+    country: Field,
+    subdivision: RwSignal<Option<i32>>,
+) -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+
+    // Signals for dropdown data.
+    let (countries, set_countries) = signal(Vec::<IsoCountry>::new());
+    let (subdivisions, set_subdivisions) = signal(Vec::<Subdivision>::new());
+
+    // Initially fetch the list of countries.
+    spawn_local({
+        let api = api.clone();
+        async move {
+            match api.countries().await {
+                Ok(countries) => {
+                    log!("Fetched {} countries successfully.", countries.len());
+                    set_countries.set(countries);
+                }
+                Err(err) => log!("Error fetching countries: {}", err),
+            }
+        }
+    });
+
+    // Refetch subdivisions whenever the selected country changes.
+    Effect::new(move |_| {
+        // "Select Country" has no subdivisions to fetch.
+        let Ok(country_code) = country.value.get().parse::<i32>() else {
+            set_subdivisions.set(Vec::new());
+            return;
+        };
+        let api = api.clone();
+        spawn_local(async move {
+            let result = api.subdivisions(country_code).await;
+            // Drop responses for a country that is no longer selected; `None`
+            // means the form is gone altogether.
+            let is_current = country
+                .value
+                .try_with_untracked(|value| value.parse::<i32>().ok() == Some(country_code));
+            if is_current != Some(true) {
+                return;
+            }
+            match result {
+                Ok(subdivisions) => {
+                    log!("Fetched {} subdivisions successfully.", subdivisions.len());
+                    set_subdivisions.set(subdivisions);
+                }
+                Err(err) => log!("Error fetching subdivisions: {}", err),
+            }
+        });
+    });
+
+    let on_country_change = move |ev: web_sys::Event| {
+        country.set(event_target_value(&ev));
+        subdivision.set(None);
+    };
+
+    let on_subdivision_change = move |ev: web_sys::Event| {
+        subdivision.set(event_target_value(&ev).parse::<i32>().ok());
+    };
+
+    view! {
+        <div>
+            <label for="user_country">"Country*:"</label>
+            <select
+                id="user_country"
+                on:change=on_country_change
+                on:blur=move |_| country.touch()
+            >
+                <option value="">"Select Country"</option>
+                {move || {
+                    countries
+                        .get()
+                        .into_iter()
+                        .map(|country_option| {
+                            let code = country_option.country_code.to_string();
+                            let is_selected = {
+                                let code = code.clone();
+                                move || country.value.with(|value| *value == code)
+                            };
+                            view! {
+                                <option value=code selected=is_selected>
+                                    {format!(
+                                        "{} {}",
+                                        country_option.country_flag,
+                                        country_option.country_eng_name,
+                                    )}
+                                </option>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </select>
+            <FieldErrorMessage field=country />
+        </div>
+        <div>
+            <label for="user_subdivision">"Subdivision:"</label>
+            <select id="user_subdivision" on:change=on_subdivision_change>
+                <option value="">"Select Subdivision"</option>
+                {move || {
+                    subdivisions
+                        .get()
+                        .into_iter()
+                        .map(|subdivision_option| {
+                            let id = subdivision_option.subdivision_id;
+                            view! {
+                                <option
+                                    value=id.to_string()
+                                    selected=move || subdivision.get() == Some(id)
+                                >
+                                    {subdivision_option.subdivision_name}
+                                </option>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </select>
+        </div>
+    }
+}
+
+/// Language select backed by the `/dropdown/language` list.
+#[component]
+pub fn LanguageSelect(language: Field) -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let (languages, set_languages) = signal(Vec::<IsoLanguage>::new());
+
+    // Initially fetch the list of languages.
+    spawn_local(async move {
+        match api.languages().await {
+            Ok(languages) => {
+                log!("Fetched {} languages successfully.", languages.len());
+                set_languages.set(languages);
+            }
+            Err(err) => log!("Error fetching languages: {}", err),
+        }
+    });
+
+    view! {
+        <div>
+            <label for="user_language">"Language*:"</label>
+            <select
+                id="user_language"
+                on:change=move |ev| language.set(event_target_value(&ev))
+                on:blur=move |_| language.touch()
+            >
+                <option value="">"Select Language"</option>
+                {move || {
+                    languages
+                        .get()
+                        .into_iter()
+                        .map(|lang| {
+                            let code = lang.language_code.to_string();
+                            let is_selected = {
+                                let code = code.clone();
+                                move || language.value.with(|value| *value == code)
+                            };
+                            view! {
+                                <option value=code selected=is_selected>
+                                    {lang.language_eng_name}
+                                </option>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </select>
+            <FieldErrorMessage field=language />
+        </div>
+    }
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use leptos::{ev::MouseEvent, prelude::*, task::spawn_local};
+use web_sys::{wasm_bindgen::JsCast, Element};
+
+use crate::utils::clipboard::copy_text;
+
+/// Shared by every page that shows rendered Markdown.
+pub const MARKDOWN_BODY_STYLE: &str = include_str!("./markdown_body.css");
+
+/// Rendered, already sanitized Markdown from `render_markdown`.
+#[component]
+pub fn MarkdownBody(#[prop(into)] html: Signal<String>) -> impl IntoView {
+    view! {
+        <div class="markdown-body" inner_html=move || html.get() on:click=copy_code_block></div>
+    }
+}
+
+/// Click delegation for the code blocks' copy buttons, which are plain HTML
+/// and cannot carry their own handlers.
+fn copy_code_block(ev: MouseEvent) {
+    let Some(button) = ev
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .and_then(|element| element.closest(".code-copy").ok().flatten())
+    else {
+        return;
+    };
+    let Some(code) = button
+        .closest(".code-block")
+        .ok()
+        .flatten()
+        .and_then(|block| block.query_selector("code").ok().flatten())
+    else {
+        return;
+    };
+
+    let text = code.text_content().unwrap_or_default();
+    spawn_local(async move {
+        let label = match copy_text(&text).await {
+            Ok(_) => "Copied!",
+            Err(_) => "Copy failed",
+        };
+        button.set_text_content(Some(label));
+        set_timeout(
+            move || button.set_text_content(Some("Copy")),
+            Duration::from_secs(2),
+        );
+    });
+}
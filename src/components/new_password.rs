@@ -0,0 +1,94 @@
+use leptos::prelude::*;
+
+use crate::{
+    components::field_error::FieldErrorMessage,
+    utils::{password_strength::PasswordStrength, validation::Field},
+};
+
+/// Password + confirmation inputs with a show/hide toggle and a live
+/// strength meter. The parent owns the fields and the strength memo so it
+/// can factor both into whether its submit button is enabled.
+#[component]
+pub fn NewPasswordFields(
+    password: Field,
+    confirm: Field,
+    strength: Memo<PasswordStrength>,
+    /// `id` of the password input; the confirmation gets `{id}_confirm`.
+    #[prop(into)]
+    id: String,
+    /// Label of the password input, "Password" by default.
+    #[prop(into, default = "Password".to_owned())]
+    label: String,
+) -> impl IntoView {
+    let (is_visible, set_is_visible) = signal(false);
+    let input_type = move || if is_visible.get() { "text" } else { "password" };
+    let confirm_id = format!("{}_confirm", id);
+
+    view! {
+        <div>
+            <label for=id.clone()>{format!("{}*:", label)}</label>
+            <div class="password-input">
+                <input
+                    id=id
+                    type=input_type
+                    placeholder="Your Password"
+                    on:input=move |ev| password.set(event_target_value(&ev))
+                    on:blur=move |_| password.touch()
+                />
+                <button
+                    type="button"
+                    class="password-toggle"
+                    on:click=move |_| set_is_visible.update(|v| *v = !*v)
+                >
+                    {move || if is_visible.get() { "Hide" } else { "Show" }}
+                </button>
+            </div>
+            <FieldErrorMessage field=password />
+            <PasswordStrengthMeter strength=strength />
+        </div>
+        <div>
+            <label for=confirm_id.clone()>{format!("Confirm {}*:", label)}</label>
+            <input
+                id=confirm_id
+                type=input_type
+                placeholder="Repeat Your Password"
+                on:input=move |ev| confirm.set(event_target_value(&ev))
+                on:blur=move |_| confirm.touch()
+            />
+            <FieldErrorMessage field=confirm />
+        </div>
+    }
+}
+
+/// Coloured bar plus label and suggestions for the current password.
+#[component]
+pub fn PasswordStrengthMeter(strength: Memo<PasswordStrength>) -> impl IntoView {
+    view! {
+        <div class="strength-meter">
+            <div class="strength-track">
+                <div
+                    class="strength-fill"
+                    style=move || {
+                        let strength = strength.get();
+                        format!(
+                            "width: {}%; background-color: {};",
+                            (strength.score as u32 + 1) * 20,
+                            strength.color(),
+                        )
+                    }
+                ></div>
+            </div>
+            <span class="strength-label">{move || strength.get().label()}</span>
+            <ul class="strength-suggestions">
+                {move || {
+                    strength
+                        .get()
+                        .suggestions
+                        .into_iter()
+                        .map(|suggestion| view! { <li>{suggestion}</li> })
+                        .collect_view()
+                }}
+            </ul>
+        </div>
+    }
+}
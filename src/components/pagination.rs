@@ -0,0 +1,65 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
+
+/// How many page links to show on either side of the current page.
+const PAGE_WINDOW: u32 = 2;
+
+/// The 1-based page number in `?page=`; anything missing or invalid is page 1.
+pub fn use_page_param() -> Memo<u32> {
+    let query = use_query_map();
+    Memo::new(move |_| {
+        query.with(|query| {
+            query
+                .get("page")
+                .and_then(|page| page.parse::<u32>().ok())
+                .filter(|page| *page >= 1)
+                .unwrap_or(1)
+        })
+    })
+}
+
+/// Page-number navigation rendered as plain links, so the current page is
+/// always reflected in (and restored from) the URL.
+#[component]
+pub fn Pagination(
+    /// 1-based current page.
+    #[prop(into)]
+    page: Signal<u32>,
+    #[prop(into)] total_pages: Signal<u32>,
+    /// Builds the URL of a given page.
+    #[prop(into)]
+    href: Callback<u32, String>,
+) -> impl IntoView {
+    let pages = move || {
+        let current = page.get();
+        let total = total_pages.get();
+        let first = current.saturating_sub(PAGE_WINDOW).max(1);
+        let last = (current + PAGE_WINDOW).min(total);
+        (first..=last).collect::<Vec<_>>()
+    };
+
+    view! {
+        <Show when=move || { total_pages.get() > 1 }>
+            <nav class="pagination">
+                <Show when=move || { page.get() > 1 }>
+                    <a href=move || href.run(page.get() - 1)>"← Prev"</a>
+                </Show>
+                {move || {
+                    pages()
+                        .into_iter()
+                        .map(|number| {
+                            if number == page.get() {
+                                view! { <span class="current">{number}</span> }.into_any()
+                            } else {
+                                view! { <a href=href.run(number)>{number}</a> }.into_any()
+                            }
+                        })
+                        .collect_view()
+                }}
+                <Show when=move || { page.get() < total_pages.get() }>
+                    <a href=move || href.run(page.get() + 1)>"Next →"</a>
+                </Show>
+            </nav>
+        </Show>
+    }
+}
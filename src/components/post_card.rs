@@ -0,0 +1,75 @@
+use chrono::Local;
+use leptos::prelude::*;
+
+use crate::dto::post::PostSummary;
+
+/// Shared by every page that lists posts.
+pub const POST_CARD_STYLE: &str = include_str!("./post_card.css");
+
+/// A post teaser linking to the full post.
+#[component]
+pub fn PostCard(post: PostSummary) -> impl IntoView {
+    let href = format!("/posts/{}", post.slug);
+    let published = post
+        .published_at
+        .with_timezone(&Local)
+        .format("%Y-%m-%d")
+        .to_string();
+
+    view! {
+        <article class="post-card">
+            <h2 class="post-card-title">
+                <a href=href.clone()>{post.title}</a>
+            </h2>
+            <p class="post-card-meta">
+                {format!(
+                    "{} · {} · {} min read",
+                    post.author_name,
+                    published,
+                    post.read_time_minutes,
+                )}
+            </p>
+            <p class="post-card-excerpt">{post.excerpt}</p>
+            <TagList tags=post.tags />
+            <a class="post-card-more" href=href>
+                "Read more →"
+            </a>
+        </article>
+    }
+}
+
+/// A post's tags as chips linking to `/tags/:tag`.
+#[component]
+pub fn TagList(tags: Vec<String>) -> impl IntoView {
+    view! {
+        <ul class="tag-list">
+            {tags
+                .into_iter()
+                .map(|tag| {
+                    let href = format!("/tags/{}", urlencoding::encode(&tag));
+                    view! {
+                        <li>
+                            <a class="tag-chip" href=href>
+                                {format!("#{}", tag)}
+                            </a>
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+}
+
+/// Grey placeholder with the shape of a `PostCard`, shown while loading.
+#[component]
+pub fn PostCardSkeleton() -> impl IntoView {
+    view! {
+        <div class="post-card skeleton" aria-hidden="true">
+            <div class="skeleton-line title"></div>
+            <div class="skeleton-line meta"></div>
+            <div class="skeleton-line"></div>
+            <div class="skeleton-line"></div>
+            <div class="skeleton-line short"></div>
+        </div>
+    }
+}
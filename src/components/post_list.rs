@@ -0,0 +1,98 @@
+use leptos::{logging::log, prelude::*, task::spawn_local};
+
+use crate::{
+    api::client::ApiClient,
+    components::{
+        pagination::{use_page_param, Pagination},
+        post_card::{PostCard, PostCardSkeleton, POST_CARD_STYLE},
+    },
+    dto::post::PostPage,
+};
+
+const PAGE_SIZE: u32 = 10;
+
+/// Which posts a `PostList` shows.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PostFeed {
+    /// Every published post, newest first.
+    Latest,
+    /// Posts carrying this tag.
+    Tagged(String),
+}
+
+impl PostFeed {
+    fn empty_message(&self) -> &'static str {
+        match self {
+            PostFeed::Latest => "No posts yet.",
+            PostFeed::Tagged(_) => "No posts with this tag yet.",
+        }
+    }
+}
+
+/// A paginated list of post cards; the page lives in `?page=`.
+#[component]
+pub fn PostList(
+    #[prop(into)] feed: Signal<PostFeed>,
+    /// Builds the URL of a given page.
+    #[prop(into)]
+    href: Callback<u32, String>,
+) -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let page = use_page_param();
+
+    // `None` while the current page is loading.
+    let (posts, set_posts) = signal(None::<Result<PostPage, String>>);
+
+    Effect::new(move |_| {
+        let requested = (feed.get(), page.get());
+        let api = api.clone();
+        set_posts.set(None);
+        spawn_local(async move {
+            let (requested_feed, requested_page) = &requested;
+            let result = match requested_feed {
+                PostFeed::Latest => api.posts(*requested_page, PAGE_SIZE).await,
+                PostFeed::Tagged(tag) => api.posts_with_tag(tag, *requested_page, PAGE_SIZE).await,
+            };
+            // Drop responses for a page the user has already moved away from,
+            // or for a list that has been unmounted meanwhile.
+            let current = feed.try_get_untracked().zip(page.try_get_untracked());
+            if current.as_ref() != Some(&requested) {
+                return;
+            }
+            set_posts.set(Some(result.map_err(|err| {
+                log!("Fetching posts failed: {}", err);
+                err.user_message()
+            })));
+        });
+    });
+
+    let total_pages = move || {
+        posts.with(|posts| match posts {
+            Some(Ok(post_page)) => post_page.total_pages,
+            _ => 0,
+        })
+    };
+
+    view! {
+        <style>{POST_CARD_STYLE}</style>
+        {move || match posts.get() {
+            None => (0..3).map(|_| view! { <PostCardSkeleton /> }).collect_view().into_any(),
+            Some(Err(message)) => view! { <p class="list-message">{message}</p> }.into_any(),
+            Some(Ok(post_page)) if post_page.posts.is_empty() => {
+                view! {
+                    <p class="list-message">{feed.with(PostFeed::empty_message)}</p>
+                }
+                    .into_any()
+            }
+            Some(Ok(post_page)) => {
+                post_page
+                    .posts
+                    .into_iter()
+                    .map(|post| view! { <PostCard post=post /> })
+                    .collect_view()
+                    .into_any()
+            }
+        }}
+        <Pagination page=page total_pages=Signal::derive(total_pages) href=href />
+    }
+}
@@ -0,0 +1,103 @@
+use leptos::{logging::log, prelude::*, task::spawn_local};
+
+use crate::{
+    api::client::ApiClient,
+    components::field_error::FieldErrorMessage,
+    dto::auth::ReauthenticateRequest,
+    utils::{
+        time::use_clock,
+        validation::{Field, Rule},
+    },
+    GlobalAppState,
+};
+
+/// Shows its children only while the user has recently confirmed their
+/// password; otherwise asks for it first. The window itself is granted and
+/// enforced by the backend, this just mirrors it to avoid a pointless round trip.
+#[component]
+pub fn ReauthGate(children: ChildrenFn) -> impl IntoView {
+    let global_state =
+        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
+    let now = use_clock();
+
+    let is_fresh = move || {
+        global_state.with(|state| {
+            state
+                .reauthenticated_until
+                .is_some_and(|until| until > now.get())
+        })
+    };
+
+    view! {
+        <Show when=is_fresh fallback=ReauthForm>
+            {children()}
+        </Show>
+    }
+}
+
+/// Marks the re-authentication window as closed, e.g. after the backend
+/// answered `REAUTH_REQUIRED`, so `ReauthGate` prompts again.
+pub fn expire_reauth(set_global_state: WriteSignal<GlobalAppState>) {
+    set_global_state.update(|state| state.reauthenticated_until = None);
+}
+
+#[component]
+fn ReauthForm() -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let set_global_state =
+        use_context::<WriteSignal<GlobalAppState>>().expect("global_state setter not provided");
+
+    let user_password = Field::new(vec![Rule::Required]);
+    let (error_message, set_error_message) = signal(None::<String>);
+    let (is_sending, set_is_sending) = signal(false);
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if !user_password.is_valid() {
+            return;
+        }
+        let request = ReauthenticateRequest {
+            user_password: user_password.value.get(),
+        };
+        let api = api.clone();
+        set_is_sending.set(true);
+        set_error_message.set(None);
+        spawn_local(async move {
+            match api.reauthenticate(&request).await {
+                Ok(resp) => {
+                    set_global_state
+                        .update(|state| state.reauthenticated_until = Some(resp.valid_until));
+                }
+                Err(err) => {
+                    log!("Re-authentication failed: {}", err);
+                    set_error_message.set(Some(err.user_message()));
+                }
+            }
+            set_is_sending.set(false);
+        });
+    };
+
+    view! {
+        <form on:submit=on_submit>
+            <p>"Please confirm your password to continue."</p>
+            {move || error_message.get().map(|msg| view! { <p class="form-error">{msg}</p> })}
+            <div>
+                <label for="reauth_password">"Current Password*:"</label>
+                <input
+                    id="reauth_password"
+                    type="password"
+                    placeholder="Your Password"
+                    on:input=move |ev| user_password.set(event_target_value(&ev))
+                    on:blur=move |_| user_password.touch()
+                />
+                <FieldErrorMessage field=user_password />
+            </div>
+            <button
+                type="submit"
+                disabled=move || is_sending.get() || !user_password.is_valid()
+            >
+                "Confirm"
+            </button>
+        </form>
+    }
+}
@@ -0,0 +1,142 @@
+use leptos::prelude::*;
+use leptos_router::{
+    components::ProtectedRoute as RouterProtectedRoute,
+    hooks::{use_location, use_query_map},
+    MatchNestedRoutes, PossibleRouteMatch,
+};
+use urlencoding::encode;
+
+use crate::GlobalAppState;
+
+/// Where to send a user after logging in, taken from the `next` query
+/// parameter. Only same-origin absolute paths are accepted so the parameter
+/// cannot be used as an open redirect. Backslashes and control characters are
+/// refused outright: URL parsers treat `/\evil.com` like `//evil.com` and
+/// drop tabs and newlines, so a tab between two slashes would be too.
+pub fn next_path(next: Option<String>) -> String {
+    match next {
+        Some(next)
+            if next.starts_with('/')
+                && !next.starts_with("//")
+                && !next.chars().any(|c| c == '\\' || c.is_control()) =>
+        {
+            next
+        }
+        _ => String::from("/"),
+    }
+}
+
+/// Resolves once the boot-time session check has come back.
+///
+/// Guards only re-evaluate when this flips: logging in or out from inside a
+/// guarded page is followed by that page's own navigation, and re-running the
+/// guard at the same time would race it.
+fn session_checked() -> Memo<bool> {
+    let global_state =
+        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
+    Memo::new(move |_| global_state.with(|state| state.is_session_checked))
+}
+
+fn is_logged_in() -> bool {
+    use_context::<ReadSignal<GlobalAppState>>()
+        .expect("global_state not provided")
+        .with_untracked(|state| state.is_logged_in)
+}
+
+/// Shown by both guards while the session check is still pending.
+fn checking_session() -> impl IntoView {
+    view! { <p style="text-align: center; color: #aaa;">"Checking session…"</p> }
+}
+
+/// A `Route` that only logged-in users may see. Anyone else is sent to the
+/// login page with `?next=` pointing back here.
+#[component(transparent)]
+pub fn ProtectedRoute<Segments, ViewFn, View>(
+    path: Segments,
+    view: ViewFn,
+) -> impl MatchNestedRoutes + Clone
+where
+    Segments: PossibleRouteMatch + Clone + Send + 'static,
+    ViewFn: Fn() -> View + Send + Clone + 'static,
+    View: IntoView + 'static,
+{
+    let checked = session_checked();
+    let condition = move || checked.get().then(is_logged_in);
+    let redirect_path = move || {
+        let location = use_location();
+        let here = format!(
+            "{}{}",
+            location.pathname.get_untracked(),
+            location.search.get_untracked()
+        );
+        format!("/account/login?next={}", encode(&here))
+    };
+
+    view! {
+        <RouterProtectedRoute
+            path=path
+            view=view
+            condition=condition
+            redirect_path=redirect_path
+            fallback=checking_session
+        />
+    }
+    .into_inner()
+}
+
+/// A `Route` only for visitors who are not logged in (login, signup, ...).
+/// Logged-in users are sent on to `?next=` if present, or home otherwise.
+#[component(transparent)]
+pub fn AnonymousOnlyRoute<Segments, ViewFn, View>(
+    path: Segments,
+    view: ViewFn,
+) -> impl MatchNestedRoutes + Clone
+where
+    Segments: PossibleRouteMatch + Clone + Send + 'static,
+    ViewFn: Fn() -> View + Send + Clone + 'static,
+    View: IntoView + 'static,
+{
+    let checked = session_checked();
+    let condition = move || checked.get().then(|| !is_logged_in());
+    let redirect_path = move || next_path(use_query_map().get_untracked().get("next"));
+
+    view! {
+        <RouterProtectedRoute
+            path=path
+            view=view
+            condition=condition
+            redirect_path=redirect_path
+            fallback=checking_session
+        />
+    }
+    .into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_path;
+
+    #[test]
+    fn keeps_same_origin_paths() {
+        assert_eq!(
+            next_path(Some("/posts/new?x=1#top".into())),
+            "/posts/new?x=1#top"
+        );
+    }
+
+    #[test]
+    fn rejects_other_origins() {
+        for next in [
+            "https://evil.com",
+            "//evil.com",
+            "/\\evil.com",
+            "/\\/evil.com",
+            "/\t/evil.com",
+            "/\n/evil.com",
+            "evil.com",
+        ] {
+            assert_eq!(next_path(Some(next.into())), "/", "{:?}", next);
+        }
+        assert_eq!(next_path(None), "/");
+    }
+}
@@ -0,0 +1,237 @@
+use std::time::Duration;
+
+use leptos::{ev::KeyboardEvent, logging::log, prelude::*, task::spawn_local};
+use leptos_router::hooks::use_navigate;
+use web_sys::{AbortController, AbortSignal};
+
+use crate::{
+    api::client::ApiClient,
+    dto::search::SearchHit,
+    utils::search::{highlight_segments, query_terms},
+};
+
+/// Shared by the search box and the search results page.
+pub const SEARCH_STYLE: &str = include_str!("./search_box.css");
+
+/// How long typing has to pause before the box searches.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Shorter queries match too much to be worth a request.
+pub const MIN_QUERY_LENGTH: usize = 2;
+
+/// How many hits the dropdown shows.
+const SUGGESTION_COUNT: u32 = 5;
+
+/// `text` with every occurrence of `terms` wrapped in `<mark>`.
+#[component]
+pub fn Highlighted(#[prop(into)] text: String, terms: Vec<String>) -> impl IntoView {
+    highlight_segments(&text, &terms)
+        .into_iter()
+        .map(|(segment, is_match)| {
+            if is_match {
+                view! { <mark>{segment}</mark> }.into_any()
+            } else {
+                segment.into_any()
+            }
+        })
+        .collect_view()
+}
+
+/// The search field in the top bar, suggesting matching posts as you type.
+#[component]
+pub fn SearchBox() -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let navigate = use_navigate();
+
+    let query = RwSignal::new(String::new());
+    let hits = RwSignal::new(Vec::<SearchHit>::new());
+    let (is_loading, set_loading) = signal(false);
+    let is_open = RwSignal::new(false);
+    // Index into `hits` picked with the arrow keys.
+    let active = RwSignal::new(None::<usize>);
+
+    let debounce = StoredValue::new(None::<TimeoutHandle>);
+    let in_flight = StoredValue::new_local(None::<AbortController>);
+
+    // Drops whatever is pending: a scheduled search and a request in flight.
+    let cancel_pending = move || {
+        if let Some(handle) = debounce.get_value() {
+            handle.clear();
+        }
+        if let Some(controller) = in_flight.get_value() {
+            controller.abort();
+        }
+        in_flight.set_value(None);
+    };
+    on_cleanup(cancel_pending);
+
+    Effect::new(move |_| {
+        let text = query.get().trim().to_owned();
+        cancel_pending();
+        active.set(None);
+        if text.chars().count() < MIN_QUERY_LENGTH {
+            hits.set(Vec::new());
+            set_loading.set(false);
+            return;
+        }
+
+        let api = api.clone();
+        let handle = set_timeout_with_handle(
+            move || {
+                let controller = AbortController::new().ok();
+                in_flight.set_value(controller.clone());
+                set_loading.set(true);
+                spawn_local(async move {
+                    let signal = controller.as_ref().map(AbortController::signal);
+                    let result = api
+                        .search_posts(&text, 1, SUGGESTION_COUNT, signal.as_ref())
+                        .await;
+                    // A newer query aborted this one and owns the dropdown now.
+                    if signal.as_ref().is_some_and(AbortSignal::aborted) {
+                        return;
+                    }
+                    set_loading.set(false);
+                    match result {
+                        Ok(page) => hits.set(page.hits),
+                        Err(err) => {
+                            log!("Searching failed: {}", err);
+                            hits.set(Vec::new());
+                        }
+                    }
+                });
+            },
+            DEBOUNCE,
+        );
+        debounce.set_value(handle.ok());
+    });
+
+    let close = move || {
+        is_open.set(false);
+        active.set(None);
+    };
+    let open_post = Callback::new({
+        let navigate = navigate.clone();
+        move |slug: String| {
+            close();
+            query.set(String::new());
+            navigate(&format!("/posts/{}", slug), Default::default());
+        }
+    });
+    let open_results = Callback::new(move |()| {
+        let text = query.get_untracked().trim().to_owned();
+        if text.is_empty() {
+            return;
+        }
+        close();
+        navigate(
+            &format!("/search?q={}", urlencoding::encode(&text)),
+            Default::default(),
+        );
+    });
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let count = hits.with_untracked(Vec::len);
+        match ev.key().as_str() {
+            "ArrowDown" if count > 0 => {
+                ev.prevent_default();
+                is_open.set(true);
+                active.update(|active| *active = Some(active.map_or(0, |i| (i + 1) % count)));
+            }
+            "ArrowUp" if count > 0 => {
+                ev.prevent_default();
+                is_open.set(true);
+                active.update(|active| {
+                    *active = Some(active.map_or(count - 1, |i| (i + count - 1) % count))
+                });
+            }
+            "Enter" => {
+                ev.prevent_default();
+                let picked = active.get_untracked().and_then(|index| {
+                    hits.with_untracked(|hits| hits.get(index).map(|hit| hit.post.slug.clone()))
+                });
+                match picked {
+                    Some(slug) => open_post.run(slug),
+                    None => open_results.run(()),
+                }
+            }
+            "Escape" => close(),
+            _ => {}
+        }
+    };
+
+    let has_query = move || query.with(|query| query.trim().chars().count() >= MIN_QUERY_LENGTH);
+
+    view! {
+        <style>{SEARCH_STYLE}</style>
+        <div class="search-box">
+            <input
+                type="search"
+                placeholder="Search posts…"
+                aria-label="Search posts"
+                role="combobox"
+                aria-expanded=move || (is_open.get() && has_query()).to_string()
+                aria-controls="search-suggestions"
+                prop:value=move || query.get()
+                on:input=move |ev| {
+                    query.set(event_target_value(&ev));
+                    is_open.set(true);
+                }
+                on:focus=move |_| is_open.set(true)
+                on:blur=move |_| close()
+                on:keydown=on_keydown
+            />
+            <Show when=move || is_open.get() && has_query()>
+                // Keeps focus in the input, so clicking a hit does not blur
+                // and close the dropdown before the click lands.
+                <ul
+                    class="search-dropdown"
+                    id="search-suggestions"
+                    role="listbox"
+                    on:mousedown=|ev| ev.prevent_default()
+                >
+                    {move || {
+                        let terms = query_terms(&query.get());
+                        let rows = hits
+                            .get()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, hit)| {
+                                let slug = hit.post.slug.clone();
+                                view! {
+                                    <li
+                                        role="option"
+                                        class="search-hit"
+                                        class:active=move || active.get() == Some(index)
+                                        aria-selected=move || {
+                                            (active.get() == Some(index)).to_string()
+                                        }
+                                        on:mouseenter=move |_| active.set(Some(index))
+                                        on:click=move |_| open_post.run(slug.clone())
+                                    >
+                                        <span class="search-hit-title">
+                                            <Highlighted text=hit.post.title terms=terms.clone() />
+                                        </span>
+                                        <span class="search-hit-snippet">
+                                            <Highlighted text=hit.snippet terms=terms.clone() />
+                                        </span>
+                                    </li>
+                                }
+                            })
+                            .collect_view();
+                        if is_loading.get() && hits.with(Vec::is_empty) {
+                            view! { <li class="search-status">"Searching…"</li> }.into_any()
+                        } else if hits.with(Vec::is_empty) {
+                            view! { <li class="search-status">"No matching posts."</li> }
+                                .into_any()
+                        } else {
+                            rows.into_any()
+                        }
+                    }}
+                    <li class="search-all" on:click=move |_| open_results.run(())>
+                        {move || format!("See all results for “{}”", query.get().trim())}
+                    </li>
+                </ul>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use leptos::{html::Input, logging::log, prelude::*, task::spawn_local};
+use web_sys::HtmlInputElement;
+
+use crate::{
+    config::AppConfig,
+    utils::{
+        clipboard::copy_text,
+        feed::{feed_url, FeedFormat},
+    },
+};
+
+/// Shared by the top bar menu and the feed preview page.
+pub const SUBSCRIBE_STYLE: &str = include_str!("./subscribe_menu.css");
+
+/// "Subscribe" in the top bar: the feed URLs, ready to paste into a reader.
+#[component]
+pub fn SubscribeMenu() -> impl IntoView {
+    let (is_open, set_open) = signal(false);
+
+    view! {
+        <style>{SUBSCRIBE_STYLE}</style>
+        <div class="subscribe-menu">
+            <button
+                type="button"
+                class="subscribe-toggle"
+                aria-expanded=move || is_open.get().to_string()
+                on:click=move |_| set_open.update(|open| *open = !*open)
+            >
+                "Subscribe"
+            </button>
+            <Show when=move || is_open.get()>
+                <div class="subscribe-panel">
+                    <FeedUrlList />
+                    <a class="subscribe-preview" href="/feeds" on:click=move |_| set_open.set(false)>
+                        "Preview feeds →"
+                    </a>
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+/// Every feed's URL with a button copying it to the clipboard.
+#[component]
+pub fn FeedUrlList() -> impl IntoView {
+    let config = use_context::<AppConfig>().expect("app config not provided");
+    // The feed whose URL was just copied, and whether that worked, to
+    // acknowledge on its button.
+    let copied = RwSignal::new(None::<(FeedFormat, bool)>);
+
+    view! {
+        <ul class="feed-urls">
+            {FeedFormat::ALL
+                .into_iter()
+                .map(|format| {
+                    let url = feed_url(&config.backend_url, format);
+                    let input = NodeRef::<Input>::new();
+                    let copy = {
+                        let url = url.clone();
+                        move |_| copy_url(url.clone(), format, input, copied)
+                    };
+                    view! {
+                        <li>
+                            <span class="feed-label">{format.label()}</span>
+                            <input
+                                node_ref=input
+                                type="text"
+                                readonly
+                                value=url
+                                aria-label=format!("{} feed URL", format.label())
+                                on:focus=|ev| {
+                                    event_target::<HtmlInputElement>(&ev).select();
+                                }
+                            />
+                            <button type="button" on:click=copy>
+                                {move || match copied.get() {
+                                    Some((copied, true)) if copied == format => "Copied!",
+                                    Some((copied, false)) if copied == format => "Copy failed",
+                                    _ => "Copy",
+                                }}
+                            </button>
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+}
+
+/// Copies `url`, or selects it in `input` for copying by hand when the
+/// clipboard is unavailable (e.g. outside a secure context).
+fn copy_url(
+    url: String,
+    format: FeedFormat,
+    input: NodeRef<Input>,
+    copied: RwSignal<Option<(FeedFormat, bool)>>,
+) {
+    spawn_local(async move {
+        let result = copy_text(&url).await;
+        if let Err(err) = &result {
+            log!(
+                "Copying the feed URL failed, selecting it instead: {:?}",
+                err
+            );
+            if let Some(input) = input.try_get_untracked().flatten() {
+                input.select();
+            }
+        }
+        let outcome = (format, result.is_ok());
+        copied.set(Some(outcome));
+        set_timeout(
+            move || {
+                // Also fine after the menu has closed and disposed `copied`.
+                if copied.try_get_untracked() == Some(Some(outcome)) {
+                    copied.set(None);
+                }
+            },
+            Duration::from_secs(2),
+        );
+    });
+}
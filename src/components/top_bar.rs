@@ -1,15 +1,15 @@
-use gloo_net::http::Request;
 use leptos::{logging::log, prelude::*, task::spawn_local};
 use leptos_router::hooks::use_navigate;
 
-use crate::GlobalAppState;
+use crate::{
+    api::{client::ApiClient, session::end_session},
+    components::{search_box::SearchBox, subscribe_menu::SubscribeMenu},
+    utils::head::SITE_TITLE,
+    GlobalAppState,
+};
 
 #[component]
 pub fn TopBar() -> impl IntoView {
-    // Grab global state from context.
-    let global_state =
-        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
-
     // Styling for a fixed top header.
     let header_style = "background-color: #222; width: 100%; position: fixed; top: 0; left: 0; padding: 0; margin: 0; font-family: sans-serif;";
 
@@ -25,7 +25,7 @@ pub fn TopBar() -> impl IntoView {
                         style="display: flex; align-items: center; text-decoration: none; color: white;"
                     >
                         <span style="font-size: 24px; margin-right: 8px;">"🏠"</span>
-                        <span style="font-size: 20px;">"Younghyun's Blog"</span>
+                        <span style="font-size: 20px;">{SITE_TITLE}</span>
                     </a>
                 </div>
                 <div class="user-profile" style="display: flex; align-items: center;">
@@ -33,7 +33,7 @@ pub fn TopBar() -> impl IntoView {
                 </div>
             </div>
             <nav class="bottom-nav" style="background-color: #333; padding: 8px 15px;">
-                <ul style="list-style: none; display: flex; gap: 15px; margin: 0; padding: 0;">
+                <ul style="list-style: none; display: flex; align-items: center; gap: 15px; margin: 0; padding: 0;">
                     <li>
                         <a href="/" style="text-decoration: none; color: white;">
                             "Home"
@@ -49,6 +49,17 @@ pub fn TopBar() -> impl IntoView {
                             "Works"
                         </a>
                     </li>
+                    <li>
+                        <a href="/tags" style="text-decoration: none; color: white;">
+                            "Tags"
+                        </a>
+                    </li>
+                    <li>
+                        <SubscribeMenu />
+                    </li>
+                    <li style="margin-left: auto;">
+                        <SearchBox />
+                    </li>
                 </ul>
             </nav>
         </header>
@@ -60,46 +71,28 @@ pub fn TopBar() -> impl IntoView {
 // a clickable emoji that toggles a dropdown menu.
 #[component]
 pub fn LoggedInUserProfile() -> impl IntoView {
-    // Get both the read and write signals from context.
-    let global_state =
-        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
+    // Get the state setter and the API client from context.
     let set_global_state =
         use_context::<WriteSignal<GlobalAppState>>().expect("global state setter not provided");
+    let api = use_context::<ApiClient>().expect("api client not provided");
     // A navigator hook to programmatically change routes.
     let navigate = use_navigate();
     // Signal to track if the dropdown should be visible.
-    let (show_dropdown, set_show_dropdown) = create_signal(false);
+    let (show_dropdown, set_show_dropdown) = signal(false);
 
     // When logging out:
     // 1) Spawn an async task to call /auth/logout;
     // 2) If successful, reset global state and navigate to the home page.
     let logout = move || {
-        spawn_local({
-            let set_global_state = set_global_state.clone();
-            let navigate = navigate.clone();
-            async move {
-                let backend_url = global_state.get().backend_url;
-                let api_key = global_state.get().api_key;
-
-                let url = format!("{}/auth/logout", backend_url);
-                let response = Request::post(&url)
-                    .header("x-api-key", api_key.as_ref())
-                    .send()
-                    .await;
-                
-                match response {
-                    Ok(res) => {
-                        if res.ok() {
-                            set_global_state.set(GlobalAppState::default());
-                            navigate("/", Default::default());
-                        } else {
-                            log!("Logout failed with status: {:?}", res.status());
-                        }
-                    }
-                    Err(err) => {
-                        log!("Logout error: {:?}", err);
-                    }
+        let api = api.clone();
+        let navigate = navigate.clone();
+        spawn_local(async move {
+            match api.logout().await {
+                Ok(()) => {
+                    end_session(set_global_state);
+                    navigate("/", Default::default());
                 }
+                Err(err) => log!("Logout failed: {}", err),
             }
         });
     };
@@ -141,13 +134,17 @@ pub fn LoggedInUserProfile() -> impl IntoView {
                                         "Account Settings"
                                     </a>
                                 </li>
-                                {}
+                                <li style="padding: 5px 0;">
+                                    <a href="/posts/new" style="text-decoration: none; color: white;">
+                                        "New Post"
+                                    </a>
+                                </li>
                             </ul>
                         </div>
                     }
                         .into_any()
                 } else {
-                    view! { <></> }.into_any()
+                    ().into_any()
                 }
             }}
         </div>
@@ -192,7 +189,10 @@ pub fn UserProfile() -> impl IntoView {
         {move || {
             let state = global_state.get();
             log!("UserProfile re-render: global_state changed to {:?}", state);
-            if state.is_logged_in {
+            if !state.is_session_checked {
+                // Still waiting on the boot-time session check.
+                view! { <span style="color: #aaa;">"Checking session…"</span> }.into_any()
+            } else if state.is_logged_in {
                 view! { <LoggedInUserProfile /> }.into_any()
             } else {
                 view! { <LoggedOutUserProfile /> }.into_any()
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use leptos::logging::log;
+
+/// The optional runtime override, served next to `index.html`. Absolute, so
+/// a deep link like `/posts/42/edit` does not look for it under `/posts/42/`.
+const RUNTIME_CONFIG_PATH: &str = "/config.json";
+
+/// Deployment environment the bundle is running in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Profile {
+    Dev,
+    Staging,
+    Prod,
+}
+
+impl Profile {
+    fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "dev" | "development" => Some(Profile::Dev),
+            "staging" => Some(Profile::Staging),
+            "prod" | "production" => Some(Profile::Prod),
+            _ => None,
+        }
+    }
+
+    /// Used when neither the build env nor `config.json` names a backend.
+    /// Dev talks to a local backend; deployed profiles assume the backend is
+    /// reverse-proxied on the same origin.
+    fn default_backend_url(self) -> &'static str {
+        match self {
+            Profile::Dev => "http://localhost:3000",
+            Profile::Staging | Profile::Prod => "",
+        }
+    }
+
+    fn default_api_key(self) -> &'static str {
+        match self {
+            Profile::Dev => "45bb0239-8e56-4600-adc9-763795430e0a",
+            Profile::Staging | Profile::Prod => "",
+        }
+    }
+}
+
+/// Where the frontend finds its backend. Kept apart from `GlobalAppState`
+/// and never persisted, so a redeploy takes effect on the next page load.
+#[derive(Clone, Debug)]
+pub struct AppConfig {
+    pub profile: Profile,
+    pub backend_url: String,
+    pub api_key: String,
+}
+
+/// Settings for a single profile in `config.json`; missing fields fall back
+/// to the build-time values.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+struct ProfileConfig {
+    backend_url: Option<String>,
+    api_key: Option<String>,
+}
+
+/// Shape of `config.json`:
+///
+/// ```json
+/// {
+///     "profile": "staging",
+///     "profiles": {
+///         "staging": { "backend_url": "https://staging.example.com", "api_key": "..." },
+///         "prod": { "backend_url": "https://api.example.com", "api_key": "..." }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+struct RuntimeConfig {
+    profile: Option<Profile>,
+    #[serde(default)]
+    profiles: HashMap<Profile, ProfileConfig>,
+}
+
+impl AppConfig {
+    /// Configuration baked in at compile time through `APP_PROFILE`,
+    /// `APP_BACKEND_URL` and `APP_API_KEY` (e.g. `APP_PROFILE=prod trunk build`).
+    pub fn from_build_env() -> Self {
+        let profile = option_env!("APP_PROFILE")
+            .and_then(Profile::parse)
+            .unwrap_or(Profile::Dev);
+        let defaults = Self::for_profile(profile);
+        Self {
+            profile,
+            backend_url: option_env!("APP_BACKEND_URL")
+                .map(|url| url.trim_end_matches('/').to_owned())
+                .unwrap_or(defaults.backend_url),
+            api_key: option_env!("APP_API_KEY")
+                .map(str::to_owned)
+                .unwrap_or(defaults.api_key),
+        }
+    }
+
+    /// The built-in defaults for `profile`, ignoring the build env.
+    fn for_profile(profile: Profile) -> Self {
+        Self {
+            profile,
+            backend_url: profile.default_backend_url().to_owned(),
+            api_key: profile.default_api_key().to_owned(),
+        }
+    }
+
+    /// Build-time configuration with `config.json` applied on top, if the
+    /// deployment ships one.
+    pub async fn load() -> Self {
+        let build = Self::from_build_env();
+        match fetch_runtime_config().await {
+            Some(runtime) => build.apply(runtime),
+            None => build,
+        }
+    }
+
+    fn apply(self, runtime: RuntimeConfig) -> Self {
+        let mut config = match runtime.profile {
+            Some(profile) if profile != self.profile => Self::for_profile(profile),
+            _ => self,
+        };
+        if let Some(overrides) = runtime.profiles.get(&config.profile) {
+            if let Some(backend_url) = &overrides.backend_url {
+                config.backend_url = backend_url.trim_end_matches('/').to_owned();
+            }
+            if let Some(api_key) = &overrides.api_key {
+                config.api_key = api_key.clone();
+            }
+        }
+        config
+    }
+}
+
+async fn fetch_runtime_config() -> Option<RuntimeConfig> {
+    let response = gloo_net::http::Request::get(RUNTIME_CONFIG_PATH)
+        .send()
+        .await
+        .ok()?;
+    // No config.json is fine; the build-time values are used as-is. An SPA
+    // fallback answers a missing file with `index.html`, so anything that is
+    // not JSON counts as missing too.
+    let is_json = response
+        .headers()
+        .get("content-type")
+        .is_some_and(|content_type| content_type.to_ascii_lowercase().contains("json"));
+    if !response.ok() || !is_json {
+        return None;
+    }
+    match response.json::<RuntimeConfig>().await {
+        Ok(runtime) => Some(runtime),
+        Err(err) => {
+            log!("Ignoring malformed {}: {:?}", RUNTIME_CONFIG_PATH, err);
+            None
+        }
+    }
+}
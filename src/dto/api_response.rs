@@ -1,9 +1,13 @@
-#[derive(Clone, Debug, serde::Deserialize)]
-#[serde(bound(deserialize = "T: serde::de::DeserializeOwned + std::fmt::Debug"))]
-pub struct ResponseFormat<T: serde::de::DeserializeOwned + std::fmt::Debug> {
-    pub success: bool,
-    pub data: T,
-    pub meta: Meta,
+use serde::de::DeserializeOwned;
+
+/// The envelope every backend response is wrapped in.
+///
+/// On the wire both cases look like `{ "success": bool, "data": ..., "meta": ... }`;
+/// `data` carries the payload when `success` is true and an `ErrorBody` otherwise.
+#[derive(Clone, Debug)]
+pub enum ResponseFormat<T: DeserializeOwned + std::fmt::Debug> {
+    Success { data: T, meta: Meta },
+    Failure { error: ErrorBody, meta: Meta },
 }
 
 #[derive(Clone, serde::Deserialize, Debug)]
@@ -11,3 +15,49 @@ pub struct Meta {
     pub time_to_process: String,
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
+
+/// What the backend sends in `data` when `success` is false.
+#[derive(Clone, serde::Deserialize, Debug, Default)]
+pub struct ErrorBody {
+    pub error_code: String,
+    pub message: String,
+    #[serde(default)]
+    pub field_errors: Vec<FieldError>,
+    #[serde(default)]
+    pub request_id: Option<String>,
+}
+
+/// A validation failure tied to one field of the submitted request.
+#[derive(Clone, serde::Deserialize, Debug)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// Raw shape used to decide which variant to decode `data` into.
+#[derive(serde::Deserialize)]
+struct RawResponseFormat {
+    success: bool,
+    #[serde(default)]
+    data: serde_json::Value,
+    meta: Meta,
+}
+
+impl<'de, T: DeserializeOwned + std::fmt::Debug> serde::Deserialize<'de> for ResponseFormat<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawResponseFormat::deserialize(deserializer)?;
+        if raw.success {
+            let data = serde_json::from_value(raw.data).map_err(serde::de::Error::custom)?;
+            Ok(ResponseFormat::Success {
+                data,
+                meta: raw.meta,
+            })
+        } else {
+            let error = serde_json::from_value(raw.data).map_err(serde::de::Error::custom)?;
+            Ok(ResponseFormat::Failure {
+                error,
+                meta: raw.meta,
+            })
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Request sent to the backend for login.
+#[derive(serde_derive::Deserialize, serde_derive::Serialize, Clone, Debug)]
+pub struct LoginRequest {
+    pub user_email: String,
+    pub user_password: String,
+}
+
+/// Expected login response from the backend.
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct LoginResponse {
+    pub message: String,
+    pub user_id: Uuid,
+}
+
+/// The request struct that you will send off to the backend.
+#[derive(serde_derive::Deserialize, serde_derive::Serialize, Clone, Debug, Default)]
+pub struct SignupRequest {
+    pub user_name: String,
+    pub user_email: String,
+    pub user_password: String,
+    pub user_country: i32,
+    pub user_language: i32,
+    pub user_subdivision: Option<i32>,
+}
+
+/// Struct representing the response from the signup endpoint.
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct SignupResponse {
+    pub user_name: String,
+    pub user_email: String,
+    pub verify_by: String,
+}
+
+/// Returned by the session-check endpoint for a live session cookie.
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct SessionResponse {
+    pub user_id: Uuid,
+    pub user_email: String,
+}
+
+/// Sent to the backend with the token from the verification email link.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct VerifyEmailRequest {
+    pub email_validation_token: String,
+}
+
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct VerifyEmailResponse {
+    pub user_email: String,
+}
+
+/// Asks the backend to send a fresh verification email.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct ResendVerificationRequest {
+    pub user_email: String,
+}
+
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct ResendVerificationResponse {
+    pub user_email: String,
+    pub verify_by: String,
+}
+
+/// Confirms the password of the logged-in user before a sensitive change.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct ReauthenticateRequest {
+    pub user_password: String,
+}
+
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct ReauthenticateResponse {
+    /// Credential changes are accepted without another prompt until then.
+    pub valid_until: DateTime<Utc>,
+}
+
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct ChangePasswordRequest {
+    pub new_password: String,
+}
+
+/// Starts an email change; the backend mails a verification link to `new_email`.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct ChangeEmailRequest {
+    pub new_email: String,
+}
+
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct ChangeEmailResponse {
+    pub new_email: String,
+    pub verify_by: String,
+}
+
+/// Asks the backend to mail a password reset link, if the account exists.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct ForgotPasswordRequest {
+    pub user_email: String,
+}
+
+/// Sets a new password using the token from a reset link.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct ResetPasswordRequest {
+    pub password_reset_token: String,
+    pub new_password: String,
+}
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A comment on a post. Replies point at their parent; the backend returns
+/// a post's comments as one flat list.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct Comment {
+    pub comment_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub author_name: String,
+    /// Markdown-lite source; empty once deleted.
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub edited_at: Option<DateTime<Utc>>,
+    /// Deleted comments that still have replies stay in the list as tombstones.
+    pub is_deleted: bool,
+}
+
+/// Sent to `POST /posts/{post_id}/comments`.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct CreateCommentRequest {
+    pub parent_id: Option<Uuid>,
+    pub body: String,
+}
+
+/// Sent to `PATCH /comments/{comment_id}`.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct UpdateCommentRequest {
+    pub body: String,
+}
@@ -0,0 +1,36 @@
+/// Country and subdivision types
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct CountryData {
+    pub countries: Vec<IsoCountry>,
+}
+
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct IsoCountry {
+    pub country_code: i32,
+    pub country_alpha2: String,
+    pub country_alpha3: String,
+    pub country_eng_name: String,
+    pub country_currency: i32,
+    pub phone_prefix: String,
+    pub country_flag: String,
+    pub is_country: bool,
+    pub country_primary_language: i32,
+}
+
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct Subdivision {
+    pub subdivision_id: i32,
+    pub country_code: i32,
+    pub subdivision_code: String,
+    pub subdivision_name: String,
+    pub subdivision_type: Option<String>,
+}
+
+/// ISO Languages: This is the reply by the backend.
+#[derive(Clone, serde_derive::Deserialize, Debug)]
+pub struct IsoLanguage {
+    pub language_code: i32,
+    pub language_alpha2: String,
+    pub language_alpha3: String,
+    pub language_eng_name: String,
+}
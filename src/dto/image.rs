@@ -0,0 +1,9 @@
+use uuid::Uuid;
+
+/// Returned by `POST /images` once the image is stored.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct UploadedImage {
+    pub image_id: Uuid,
+    /// Public URL to reference from Markdown.
+    pub url: String,
+}
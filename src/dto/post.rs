@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A post as shown in listings: everything but the body.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct PostSummary {
+    pub post_id: Uuid,
+    pub slug: String,
+    pub title: String,
+    pub excerpt: String,
+    pub author_name: String,
+    pub published_at: DateTime<Utc>,
+    pub tags: Vec<String>,
+    pub read_time_minutes: u32,
+}
+
+/// One page of a post listing.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct PostPage {
+    pub posts: Vec<PostSummary>,
+    pub page: u32,
+    pub page_size: u32,
+    pub total_pages: u32,
+}
+
+/// A full post, with its body as Markdown to be rendered client-side.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct Post {
+    pub post_id: Uuid,
+    pub slug: String,
+    pub title: String,
+    pub excerpt: String,
+    pub author_name: String,
+    pub published_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub tags: Vec<String>,
+    pub read_time_minutes: u32,
+    pub body_markdown: String,
+}
+
+/// A post as its author edits it, unpublished drafts included.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct EditablePost {
+    pub post_id: Uuid,
+    pub slug: String,
+    pub title: String,
+    pub excerpt: String,
+    pub tags: Vec<String>,
+    pub body_markdown: String,
+    pub is_published: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Sent to `POST /posts` and `PATCH /posts/{post_id}`; carries every
+/// editable value.
+#[derive(serde_derive::Deserialize, serde_derive::Serialize, Clone, Debug, Default, PartialEq)]
+pub struct SavePostRequest {
+    pub title: String,
+    pub slug: String,
+    pub excerpt: String,
+    pub tags: Vec<String>,
+    pub body_markdown: String,
+}
+
+/// Sent to `PATCH /posts/{post_id}/published`.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct SetPublishedRequest {
+    pub is_published: bool,
+}
+
+/// A tag and how many published posts carry it.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct TagCount {
+    pub tag: String,
+    pub post_count: u32,
+}
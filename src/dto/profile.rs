@@ -0,0 +1,64 @@
+use chrono::NaiveDate;
+
+/// The structured résumé behind the About page.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct ProfileDocument {
+    pub name: String,
+    pub headline: String,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+    /// Rendered with `render_markdown`.
+    pub bio_markdown: String,
+    #[serde(default)]
+    pub skills: Vec<SkillGroup>,
+    /// Most recent first.
+    #[serde(default)]
+    pub experience: Vec<Experience>,
+    /// Most recent first.
+    #[serde(default)]
+    pub education: Vec<Education>,
+    #[serde(default)]
+    pub contacts: Vec<ContactLink>,
+}
+
+/// Skills under one heading, e.g. "Languages".
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct SkillGroup {
+    pub name: String,
+    pub skills: Vec<String>,
+}
+
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct Experience {
+    pub role: String,
+    pub organization: String,
+    #[serde(default)]
+    pub location: Option<String>,
+    pub started_on: NaiveDate,
+    /// `None` for the current position.
+    #[serde(default)]
+    pub finished_on: Option<NaiveDate>,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct Education {
+    pub institution: String,
+    pub degree: String,
+    pub started_on: NaiveDate,
+    #[serde(default)]
+    pub finished_on: Option<NaiveDate>,
+}
+
+/// An email address, profile or site to reach the author at.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct ContactLink {
+    pub label: String,
+    /// `https:` or `mailto:`; anything else is not linked.
+    pub url: String,
+}
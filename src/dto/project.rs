@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+/// A portfolio project shown on the Works page.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct Project {
+    pub project_id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub tech_stack: Vec<String>,
+    pub repo_url: Option<String>,
+    pub demo_url: Option<String>,
+    /// In display order; the first one doubles as the card thumbnail.
+    pub screenshots: Vec<Screenshot>,
+    pub started_on: NaiveDate,
+    /// `None` while the project is ongoing.
+    pub finished_on: Option<NaiveDate>,
+}
+
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct Screenshot {
+    pub url: String,
+    pub caption: Option<String>,
+}
@@ -0,0 +1,20 @@
+use crate::dto::post::PostSummary;
+
+/// One search result: the post and the passage of it that matched.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct SearchHit {
+    #[serde(flatten)]
+    pub post: PostSummary,
+    /// Plain-text excerpt around the best match.
+    pub snippet: String,
+}
+
+/// One page of search results.
+#[derive(Clone, serde_derive::Deserialize, Debug, PartialEq)]
+pub struct SearchPage {
+    pub hits: Vec<SearchHit>,
+    pub page: u32,
+    pub page_size: u32,
+    pub total_pages: u32,
+    pub total_hits: u64,
+}
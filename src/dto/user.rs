@@ -0,0 +1,19 @@
+/// The logged-in user's editable profile, as returned by `/user/profile`.
+#[derive(serde_derive::Deserialize, serde_derive::Serialize, Clone, Debug, PartialEq)]
+pub struct UserProfile {
+    pub user_name: String,
+    pub user_email: String,
+    pub user_country: i32,
+    pub user_subdivision: Option<i32>,
+    pub user_language: i32,
+}
+
+/// Sent to `PATCH /user/profile`; carries the full set of editable values.
+/// The email is changed separately, through a verified flow.
+#[derive(serde_derive::Serialize, Clone, Debug)]
+pub struct UpdateProfileRequest {
+    pub user_name: String,
+    pub user_country: i32,
+    pub user_subdivision: Option<i32>,
+    pub user_language: i32,
+}
@@ -1,35 +1,95 @@
+#![allow(clippy::module_inception)]
+
+use api::{client::ApiClient, session::revive_session};
+use chrono::{DateTime, Utc};
+use components::document_head::{provide_head, use_head, DocumentHead, PageMeta};
+use components::route_guards::{AnonymousOnlyRoute, ProtectedRoute};
 use components::top_bar::TopBar;
+use config::AppConfig;
 use gloo_storage::{LocalStorage, Storage};
-use leptos::prelude::*;
+use leptos::{logging::log, prelude::*, task::spawn_local};
 use leptos_router::components::*;
 use leptos_router::path;
 
 use pages::about::about::About;
+use pages::editor::editor::PostEditor;
+use pages::feeds::feeds::Feeds;
 use pages::home::home::Home;
 use pages::login::login::Login;
+use pages::password_reset::forgot_password::ForgotPassword;
+use pages::password_reset::reset_password::ResetPassword;
+use pages::post::post::PostDetail;
+use pages::search::search::Search;
+use pages::settings::change_email::ChangeEmail;
+use pages::settings::change_password::ChangePassword;
+use pages::settings::settings::Settings;
 use pages::signup::signup::Signup;
 use pages::signup::signup_complete::SignupComplete;
+use pages::tags::tag_posts::TagPosts;
+use pages::tags::tags::Tags;
 use pages::validate_email::validate_email::ValidateEmail;
 use pages::works::works::Works;
+use utils::feed::install_feed_links;
+use utils::head::SITE_TITLE;
 use uuid::Uuid;
 
 pub mod components {
+    pub mod comments;
+    pub mod document_head;
+    pub mod field_error;
+    pub mod location_select;
+    pub mod markdown_body;
+    pub mod new_password;
+    pub mod pagination;
+    pub mod post_card;
+    pub mod post_list;
+    pub mod reauth_gate;
+    pub mod route_guards;
+    pub mod search_box;
+    pub mod subscribe_menu;
     pub mod top_bar;
 }
 pub mod pages {
     pub mod about {
         pub mod about;
     }
+    pub mod editor {
+        pub mod editor;
+        pub mod image_upload;
+    }
+    pub mod feeds {
+        pub mod feeds;
+    }
     pub mod home {
         pub mod home;
     }
     pub mod login {
         pub mod login;
     }
+    pub mod password_reset {
+        pub mod forgot_password;
+        pub mod reset_password;
+    }
+    pub mod post {
+        pub mod post;
+    }
+    pub mod search {
+        pub mod search;
+    }
+    pub mod settings {
+        pub mod change_email;
+        pub mod change_password;
+        pub mod danger_zone;
+        pub mod settings;
+    }
     pub mod signup {
         pub mod signup;
         pub mod signup_complete;
     }
+    pub mod tags {
+        pub mod tag_posts;
+        pub mod tags;
+    }
     pub mod works {
         pub mod works;
     }
@@ -39,29 +99,69 @@ pub mod pages {
 }
 pub mod dto {
     pub mod api_response;
+    pub mod auth;
+    pub mod comment;
+    pub mod dropdown;
+    pub mod image;
+    pub mod post;
+    pub mod profile;
+    pub mod project;
+    pub mod search;
+    pub mod user;
+}
+pub mod config;
+pub mod utils {
+    pub mod clipboard;
+    pub mod download;
+    pub mod feed;
+    pub mod head;
+    pub mod highlight;
+    pub mod image;
+    pub mod link;
+    pub mod markdown;
+    pub mod password_strength;
+    pub mod search;
+    pub mod time;
+    pub mod validation;
+}
+pub mod api {
+    pub mod client;
+    pub mod error;
+    pub mod session;
 }
 
-// Derive Serialize/Deserialize so we can persist this state.
-#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+/// In-memory application state. Nothing auth-related is persisted: on boot the
+/// session is revived from the backend (via the HttpOnly session cookie), so a
+/// stale or hand-edited browser storage entry can never make the UI look logged in.
+/// Backend connection details live in `config::AppConfig`, not here.
+#[derive(Clone, Debug, Default)]
 pub struct GlobalAppState {
+    /// False until the boot-time session check has come back.
+    pub is_session_checked: bool,
     pub is_logged_in: bool,
     pub user_id: Option<Uuid>,
-    pub session_id: Option<Uuid>,
     pub email: Option<String>,
-    pub backend_url: String,
-    pub api_key: String,
+    /// Until when the backend accepts credential changes without asking for
+    /// the password again. Set by `ReauthGate`.
+    pub reauthenticated_until: Option<DateTime<Utc>>,
 }
 
-impl Default for GlobalAppState {
-    fn default() -> Self {
-        Self {
-            is_logged_in: false,
-            user_id: None,
-            session_id: None,
-            email: None,
-            backend_url: String::from("http://localhost:3000"),
-            api_key: String::from("45bb0239-8e56-4600-adc9-763795430e0a"),
-        }
+impl GlobalAppState {
+    /// Marks the user as authenticated with a backend-confirmed identity.
+    pub fn sign_in(&mut self, user_id: Uuid, email: String) {
+        self.is_session_checked = true;
+        self.is_logged_in = true;
+        self.user_id = Some(user_id);
+        self.email = Some(email);
+    }
+
+    /// Drops the user's identity, e.g. after logout or when the session check fails.
+    pub fn sign_out(&mut self) {
+        self.is_session_checked = true;
+        self.is_logged_in = false;
+        self.user_id = None;
+        self.email = None;
+        self.reauthenticated_until = None;
     }
 }
 
@@ -71,21 +171,12 @@ fn main() {
 
 #[component]
 fn App() -> impl IntoView {
-    // Try to read persisted state from localStorage. If unavailable, use the default.
-    let stored_state =
-        LocalStorage::get("global_state").unwrap_or_else(|_| GlobalAppState::default());
-    let (global_state, set_global_state) = signal(stored_state);
-
-    // Provide the global state and its setter to the rest of your app.
-    provide_context(global_state);
-    provide_context(set_global_state);
-
-    // Whenever the state changes, persist it automatically.
-    create_effect({
-        let global_state = global_state.clone();
-        move |_| {
-            let _ = LocalStorage::set("global_state", &global_state.get());
-        }
+    // Resolve the backend configuration before anything can talk to it.
+    let (config, set_config) = signal(None::<AppConfig>);
+    spawn_local(async move {
+        let loaded = AppConfig::load().await;
+        log!("Loaded {:?} configuration.", loaded.profile);
+        set_config.set(Some(loaded));
     });
 
     view! {
@@ -102,10 +193,43 @@ fn App() -> impl IntoView {
             "#}
         </style>
 
+        {move || match config.get() {
+            Some(config) => view! { <Shell config=config /> }.into_any(),
+            None => view! { <p style="text-align: center; color: #aaa;">"Loading…"</p> }.into_any(),
+        }}
+    }
+}
+
+/// Everything below the configuration: app state, the API client and routes.
+#[component]
+fn Shell(config: AppConfig) -> impl IntoView {
+    // Older builds persisted the whole auth state here; make sure it's gone.
+    LocalStorage::delete("global_state");
+    let (global_state, set_global_state) = signal(GlobalAppState::default());
+
+    // Provide the global state and its setter to the rest of your app.
+    provide_context(global_state);
+    provide_context(set_global_state);
+
+    // Every page talks to the backend through this one client.
+    let api = ApiClient::new(config.backend_url.clone(), config.api_key.clone());
+    provide_context(api.clone());
+    install_feed_links(&config.backend_url, SITE_TITLE);
+    provide_context(config);
+    provide_head();
+
+    // Ask the backend whether the session cookie is still good.
+    spawn_local(async move {
+        revive_session(&api, set_global_state).await;
+    });
+
+    view! {
         <Router>
+            <DocumentHead />
             <TopBar />
 
             <Routes fallback=|| {
+                use_head(|| PageMeta::titled("Page not found"));
                 view! {
                     <div>
                         <h1>"404 - Page Not Found"</h1>
@@ -117,10 +241,22 @@ fn App() -> impl IntoView {
                 <Route path=path!("") view=Home />
                 <Route path=path!("about") view=About />
                 <Route path=path!("works") view=Works />
-                <Route path=path!("/account/signup") view=Signup />
+                <Route path=path!("feeds") view=Feeds />
+                <ProtectedRoute path=path!("/posts/new") view=PostEditor />
+                <ProtectedRoute path=path!("/posts/:slug/edit") view=PostEditor />
+                <Route path=path!("/posts/:slug") view=PostDetail />
+                <Route path=path!("/search") view=Search />
+                <Route path=path!("/tags") view=Tags />
+                <Route path=path!("/tags/:tag") view=TagPosts />
+                <AnonymousOnlyRoute path=path!("/account/signup") view=Signup />
                 <Route path=path!("/account/signup/validate-email") view=ValidateEmail/>
                 <Route path=path!("/account/signup-complete") view=SignupComplete />
-                <Route path=path!("/account/login") view=Login />
+                <AnonymousOnlyRoute path=path!("/account/login") view=Login />
+                <AnonymousOnlyRoute path=path!("/account/forgot-password") view=ForgotPassword />
+                <Route path=path!("/account/reset-password") view=ResetPassword />
+                <ProtectedRoute path=path!("/account/settings") view=Settings />
+                <ProtectedRoute path=path!("/account/settings/password") view=ChangePassword />
+                <ProtectedRoute path=path!("/account/settings/email") view=ChangeEmail />
             </Routes>
         </Router>
     }
@@ -1,11 +1,252 @@
-use leptos::prelude::*;
+use leptos::{logging::log, prelude::*, task::spawn_local};
 
+use crate::{
+    api::client::ApiClient,
+    components::{
+        document_head::{use_head, PageMeta},
+        markdown_body::{MarkdownBody, MARKDOWN_BODY_STYLE},
+    },
+    dto::profile::{ContactLink, Education, Experience, ProfileDocument, SkillGroup},
+    utils::{link::is_safe_link, markdown::render_markdown, time::format_period},
+};
+
+/// The author's résumé: bio, skills, experience and education, laid out to
+/// print cleanly as a PDF.
 #[component]
 pub fn About() -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+
+    // `None` while loading.
+    let (profile, set_profile) = signal(None::<Result<ProfileDocument, String>>);
+    // Bumped by the retry button to fetch again.
+    let (attempt, set_attempt) = signal(0_u32);
+    Effect::new(move |_| {
+        attempt.track();
+        set_profile.set(None);
+        let api = api.clone();
+        spawn_local(async move {
+            let result = api.about_profile().await.map_err(|err| {
+                log!("Fetching the profile failed: {}", err);
+                err.user_message()
+            });
+            set_profile.set(Some(result));
+        });
+    });
+
+    use_head(move || {
+        profile.with(|profile| match profile {
+            Some(Ok(profile)) => PageMeta {
+                title: Some("About".to_owned()),
+                description: Some(format!("{} — {}", profile.name, profile.headline)),
+                image: profile.avatar_url.clone(),
+                ..Default::default()
+            },
+            _ => PageMeta::titled("About"),
+        })
+    });
+
     view! {
-        <main>
-            <h1>"About Page"</h1>
-            <p>"Learn more about us on this page."</p>
+        <style>{MARKDOWN_BODY_STYLE}</style>
+        <style>{include_str!("./about.css")}</style>
+        <main class="about-page">
+            {move || match profile.get() {
+                None => view! { <p class="about-message">"Loading…"</p> }.into_any(),
+                Some(Err(message)) => {
+                    view! {
+                        <p class="about-message">{message}</p>
+                        <button
+                            type="button"
+                            class="about-retry"
+                            on:click=move |_| set_attempt.update(|attempt| *attempt += 1)
+                        >
+                            "Retry"
+                        </button>
+                    }
+                        .into_any()
+                }
+                Some(Ok(profile)) => view! { <Resume profile=profile /> }.into_any(),
+            }}
         </main>
     }
 }
+
+#[component]
+fn Resume(profile: ProfileDocument) -> impl IntoView {
+    let bio = render_markdown(&profile.bio_markdown).html;
+
+    view! {
+        <header class="about-header">
+            {profile
+                .avatar_url
+                .filter(|url| is_safe_link(url))
+                .map(|url| view! { <img class="about-avatar" src=url alt="" /> })}
+            <div class="about-identity">
+                <h1>{profile.name}</h1>
+                <p class="about-headline">{profile.headline}</p>
+                {profile.location.map(|location| view! { <p class="about-location">{location}</p> })}
+                <Contacts contacts=profile.contacts />
+            </div>
+            <button type="button" class="about-print" on:click=|_| print_page()>
+                "Download as PDF"
+            </button>
+        </header>
+
+        <section class="about-section">
+            <h2>"About"</h2>
+            <MarkdownBody html=bio />
+        </section>
+
+        {(!profile.skills.is_empty())
+            .then(|| {
+                view! {
+                    <section class="about-section">
+                        <h2>"Skills"</h2>
+                        <Skills groups=profile.skills />
+                    </section>
+                }
+            })}
+
+        {(!profile.experience.is_empty())
+            .then(|| {
+                view! {
+                    <section class="about-section">
+                        <h2>"Experience"</h2>
+                        <ol class="timeline">
+                            {profile
+                                .experience
+                                .into_iter()
+                                .map(|entry| view! { <ExperienceEntry entry=entry /> })
+                                .collect_view()}
+                        </ol>
+                    </section>
+                }
+            })}
+
+        {(!profile.education.is_empty())
+            .then(|| {
+                view! {
+                    <section class="about-section">
+                        <h2>"Education"</h2>
+                        <ol class="timeline">
+                            {profile
+                                .education
+                                .into_iter()
+                                .map(|entry| view! { <EducationEntry entry=entry /> })
+                                .collect_view()}
+                        </ol>
+                    </section>
+                }
+            })}
+    }
+}
+
+/// Opens the browser's print dialog, where "Save as PDF" is one of the targets.
+fn print_page() {
+    if let Err(err) = window().print() {
+        log!("Opening the print dialog failed: {:?}", err);
+    }
+}
+
+#[component]
+fn Contacts(contacts: Vec<ContactLink>) -> impl IntoView {
+    view! {
+        <ul class="about-contacts">
+            {contacts
+                .into_iter()
+                .map(|contact| {
+                    // The document comes from the backend.
+                    let is_safe = is_safe_link(&contact.url);
+                    let shown = contact
+                        .url
+                        .trim_start_matches("mailto:")
+                        .trim_start_matches("https://")
+                        .trim_start_matches("http://")
+                        .to_owned();
+                    let link = if is_safe {
+                        view! {
+                            <a href=contact.url target="_blank" rel="noopener noreferrer">
+                                {shown}
+                            </a>
+                        }
+                            .into_any()
+                    } else {
+                        shown.into_any()
+                    };
+                    view! {
+                        <li>
+                            <span class="about-contact-label">{contact.label}</span>
+                            {link}
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+}
+
+#[component]
+fn Skills(groups: Vec<SkillGroup>) -> impl IntoView {
+    view! {
+        <dl class="about-skills">
+            {groups
+                .into_iter()
+                .map(|group| {
+                    view! {
+                        <dt>{group.name}</dt>
+                        <dd>
+                            {group
+                                .skills
+                                .into_iter()
+                                .map(|skill| view! { <span class="skill-chip">{skill}</span> })
+                                .collect_view()}
+                        </dd>
+                    }
+                })
+                .collect_view()}
+        </dl>
+    }
+}
+
+#[component]
+fn ExperienceEntry(entry: Experience) -> impl IntoView {
+    let organization = match entry.location {
+        Some(location) => format!("{} · {}", entry.organization, location),
+        None => entry.organization,
+    };
+
+    view! {
+        <li class="timeline-entry">
+            <div class="timeline-period">{format_period(entry.started_on, entry.finished_on)}</div>
+            <div class="timeline-body">
+                <h3>{entry.role}</h3>
+                <p class="timeline-organization">{organization}</p>
+                {entry.summary.map(|summary| view! { <p>{summary}</p> })}
+                {(!entry.highlights.is_empty())
+                    .then(|| {
+                        view! {
+                            <ul class="timeline-highlights">
+                                {entry
+                                    .highlights
+                                    .into_iter()
+                                    .map(|highlight| view! { <li>{highlight}</li> })
+                                    .collect_view()}
+                            </ul>
+                        }
+                    })}
+            </div>
+        </li>
+    }
+}
+
+#[component]
+fn EducationEntry(entry: Education) -> impl IntoView {
+    view! {
+        <li class="timeline-entry">
+            <div class="timeline-period">{format_period(entry.started_on, entry.finished_on)}</div>
+            <div class="timeline-body">
+                <h3>{entry.degree}</h3>
+                <p class="timeline-organization">{entry.institution}</p>
+            </div>
+        </li>
+    }
+}
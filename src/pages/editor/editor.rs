@@ -0,0 +1,599 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Local, Utc};
+use gloo_storage::{LocalStorage, Storage};
+use leptos::{
+    ev::{ClipboardEvent, DragEvent},
+    html::Textarea,
+    logging::log,
+    prelude::*,
+    task::spawn_local,
+};
+use leptos_router::{
+    hooks::{use_location, use_navigate, use_params_map},
+    location::State,
+    NavigateOptions,
+};
+use uuid::Uuid;
+use web_sys::{
+    wasm_bindgen::{JsCast, JsValue},
+    HtmlInputElement,
+};
+
+use crate::{
+    api::client::ApiClient,
+    components::{
+        document_head::{use_head, PageMeta},
+        field_error::FieldErrorMessage,
+        markdown_body::{MarkdownBody, MARKDOWN_BODY_STYLE},
+    },
+    dto::post::{EditablePost, SavePostRequest},
+    pages::editor::image_upload::{image_files, ImageUploader, UploadProgress},
+    utils::{
+        markdown::{render_markdown, slugify},
+        validation::{all_valid, apply_field_errors, Field, Rule},
+    },
+};
+
+const EDITOR_STYLE: &str = include_str!("./editor.css");
+
+/// How long typing has to pause before the draft is written to LocalStorage.
+const AUTOSAVE_DELAY: Duration = Duration::from_secs(1);
+
+/// Unsaved edits, kept in LocalStorage so a closed tab or a crash loses
+/// nothing.
+#[derive(serde_derive::Deserialize, serde_derive::Serialize, Clone, Debug)]
+struct LocalDraft {
+    content: SavePostRequest,
+    saved_at: DateTime<Utc>,
+}
+
+/// Drafts are keyed by post id; a post that does not exist yet uses `draft:new`.
+fn draft_key(post_id: Option<Uuid>) -> String {
+    match post_id {
+        Some(post_id) => format!("draft:{}", post_id),
+        None => String::from("draft:new"),
+    }
+}
+
+fn load_draft(post_id: Option<Uuid>) -> Option<LocalDraft> {
+    LocalStorage::get(draft_key(post_id)).ok()
+}
+
+fn store_draft(post_id: Option<Uuid>, draft: &LocalDraft) {
+    if let Err(err) = LocalStorage::set(draft_key(post_id), draft) {
+        log!("Saving draft failed: {}", err);
+    }
+}
+
+fn clear_draft(post_id: Option<Uuid>) {
+    LocalStorage::delete(draft_key(post_id));
+}
+
+/// Splits `rust, wasm , leptos` into trimmed, non-empty, distinct tags.
+fn parse_tags(tags: &str) -> Vec<String> {
+    let mut parsed: Vec<String> = Vec::new();
+    for tag in tags.split(',').map(str::trim).filter(|tag| !tag.is_empty()) {
+        if !parsed.iter().any(|existing| existing == tag) {
+            parsed.push(tag.to_owned());
+        }
+    }
+    parsed
+}
+
+fn content_of(post: &EditablePost) -> SavePostRequest {
+    SavePostRequest {
+        title: post.title.clone(),
+        slug: post.slug.clone(),
+        excerpt: post.excerpt.clone(),
+        tags: post.tags.clone(),
+        body_markdown: post.body_markdown.clone(),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EditorAction {
+    Save,
+    Publish,
+    Unpublish,
+}
+
+impl EditorAction {
+    const ALL: [EditorAction; 3] = [
+        EditorAction::Save,
+        EditorAction::Publish,
+        EditorAction::Unpublish,
+    ];
+
+    /// Shown once the action has gone through.
+    fn done_message(self) -> &'static str {
+        match self {
+            EditorAction::Save => "Saved.",
+            EditorAction::Publish => "Published.",
+            EditorAction::Unpublish => "Unpublished; the post is a draft again.",
+        }
+    }
+}
+
+/// Outcome of the last backend action, shown under the buttons.
+#[derive(Clone, Debug, PartialEq)]
+enum SaveStatus {
+    Idle,
+    Saving,
+    Done(&'static str),
+    Failed(String),
+}
+
+/// Markdown editor with a live preview, at `/posts/new` and
+/// `/posts/:slug/edit`.
+#[component]
+pub fn PostEditor() -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let navigate = use_navigate();
+    let location = use_location();
+    // `None` on `/posts/new`.
+    let editing_slug = use_params_map().with_untracked(|params| params.get("slug"));
+    let heading = if editing_slug.is_some() {
+        "Edit post"
+    } else {
+        "New post"
+    };
+    use_head(move || PageMeta::titled(heading));
+
+    let title = Field::new(vec![Rule::Required, Rule::MaxLength(200)]);
+    let slug = Field::new(vec![Rule::Required, Rule::Slug, Rule::MaxLength(100)]);
+    let tags = Field::new(vec![Rule::MaxLength(200)]);
+    let excerpt = Field::new(vec![Rule::MaxLength(500)]);
+    let body = RwSignal::new(String::new());
+    // The slug follows the title until the author edits it by hand.
+    let slug_is_custom = RwSignal::new(editing_slug.is_some());
+
+    let source = NodeRef::<Textarea>::new();
+    let uploader = ImageUploader::new(api.clone(), body, source);
+    let is_dragging = RwSignal::new(false);
+
+    let post_id = RwSignal::new(None::<Uuid>);
+    let is_published = RwSignal::new(false);
+    // The content as the backend has it; `None` until loaded.
+    let saved_content = RwSignal::new(None::<SavePostRequest>);
+    // A LocalStorage draft waiting for the author to restore or discard it.
+    let pending_draft = RwSignal::new(None::<LocalDraft>);
+    let (draft_saved_at, set_draft_saved_at) = signal(None::<DateTime<Utc>>);
+    let (load_error, set_load_error) = signal(None::<String>);
+    // The first save of a new post moves from `/posts/new` to the edit route,
+    // which mounts a fresh editor; the outcome rides along in the history state.
+    let carried_status = location.state.get_untracked().to_js_value().as_string();
+    let initial_status = match carried_status {
+        None => SaveStatus::Idle,
+        Some(carried) => EditorAction::ALL
+            .into_iter()
+            .map(EditorAction::done_message)
+            .find(|message| *message == carried)
+            .map_or(SaveStatus::Failed(carried), SaveStatus::Done),
+    };
+    let (save_status, set_save_status) = signal(initial_status);
+
+    let fill_form = move |content: &SavePostRequest| {
+        title.reset(content.title.clone());
+        slug.reset(content.slug.clone());
+        tags.reset(content.tags.join(", "));
+        excerpt.reset(content.excerpt.clone());
+        body.set(content.body_markdown.clone());
+    };
+
+    let form_content = move || SavePostRequest {
+        title: title.value.get().trim().to_owned(),
+        slug: slug.value.get(),
+        excerpt: excerpt.value.get().trim().to_owned(),
+        tags: parse_tags(&tags.value.get()),
+        body_markdown: body.get(),
+    };
+
+    let is_dirty = move || {
+        saved_content.with(|saved| saved.as_ref().is_some_and(|saved| *saved != form_content()))
+    };
+    let is_busy = move || save_status.get() == SaveStatus::Saving;
+
+    match editing_slug {
+        None => {
+            pending_draft.set(load_draft(None));
+            saved_content.set(Some(SavePostRequest::default()));
+        }
+        Some(editing_slug) => spawn_local({
+            let api = api.clone();
+            async move {
+                match api.editable_post(&editing_slug).await {
+                    Ok(post) => {
+                        let content = content_of(&post);
+                        fill_form(&content);
+                        post_id.set(Some(post.post_id));
+                        is_published.set(post.is_published);
+                        pending_draft.set(
+                            load_draft(Some(post.post_id)).filter(|draft| draft.content != content),
+                        );
+                        saved_content.set(Some(content));
+                    }
+                    Err(err) => {
+                        log!("Loading post for editing failed: {}", err);
+                        set_load_error.set(Some(match err.status() {
+                            Some(403) => "Only the author can edit this post.".to_owned(),
+                            Some(404) => "Post not found.".to_owned(),
+                            _ => err.user_message(),
+                        }));
+                    }
+                }
+            }
+        }),
+    }
+
+    Effect::new(move |_| {
+        let title = title.value.get();
+        if !slug_is_custom.get() {
+            slug.reset(slugify(&title));
+        }
+    });
+
+    // Autosave to LocalStorage once typing pauses; drop the draft as soon as
+    // the form matches the backend again.
+    let autosave_timer = StoredValue::new(None::<TimeoutHandle>);
+    on_cleanup(move || {
+        if let Some(handle) = autosave_timer.get_value() {
+            handle.clear();
+        }
+    });
+    Effect::new(move |_| {
+        let content = form_content();
+        let Some(saved) = saved_content.get() else {
+            return;
+        };
+        // Leave an offered draft alone until the author decides on it.
+        if pending_draft.with(Option::is_some) {
+            return;
+        }
+        if let Some(handle) = autosave_timer.get_value() {
+            handle.clear();
+        }
+        let id = post_id.get_untracked();
+        if content == saved {
+            clear_draft(id);
+            set_draft_saved_at.set(None);
+            return;
+        }
+        let handle = set_timeout_with_handle(
+            move || {
+                let draft = LocalDraft {
+                    content,
+                    saved_at: Utc::now(),
+                };
+                store_draft(id, &draft);
+                set_draft_saved_at.set(Some(draft.saved_at));
+            },
+            AUTOSAVE_DELAY,
+        );
+        autosave_timer.set_value(handle.ok());
+    });
+
+    let restore_draft = move |_| {
+        if let Some(draft) = pending_draft.get_untracked() {
+            slug_is_custom.set(true);
+            fill_form(&draft.content);
+        }
+        pending_draft.set(None);
+    };
+    let discard_draft = move |_| {
+        clear_draft(post_id.get_untracked());
+        pending_draft.set(None);
+    };
+
+    let run = Callback::new(move |action: EditorAction| {
+        let fields = [title, slug, tags, excerpt];
+        if is_busy() || !all_valid(&fields) {
+            fields.iter().for_each(Field::touch);
+            return;
+        }
+        let content = form_content();
+        let previous_id = post_id.get_untracked();
+        let needs_save = previous_id.is_none() || is_dirty();
+        let mut saved_slug = saved_content
+            .with_untracked(|saved| saved.as_ref().map(|saved| saved.slug.clone()))
+            .unwrap_or_default();
+        let api = api.clone();
+        let navigate = navigate.clone();
+        set_save_status.set(SaveStatus::Saving);
+
+        spawn_local(async move {
+            let mut id = previous_id;
+            // Publishing and unpublishing save pending edits first.
+            if needs_save {
+                let result = match previous_id {
+                    None => api.create_post(&content).await,
+                    Some(id) => api.update_post(id, &content).await,
+                };
+                // The author left the editor while the request was out.
+                if post_id.is_disposed() {
+                    return;
+                }
+                match result {
+                    Ok(post) => {
+                        clear_draft(previous_id);
+                        clear_draft(Some(post.post_id));
+                        id = Some(post.post_id);
+                        saved_slug = post.slug.clone();
+                        post_id.set(Some(post.post_id));
+                        is_published.set(post.is_published);
+                        saved_content.set(Some(content_of(&post)));
+                    }
+                    Err(err) => {
+                        log!("Saving post failed: {}", err);
+                        let unmatched = apply_field_errors(
+                            &[
+                                ("title", title),
+                                ("slug", slug),
+                                ("tags", tags),
+                                ("excerpt", excerpt),
+                            ],
+                            err.field_errors(),
+                        );
+                        let message = unmatched
+                            .first()
+                            .map(|error| error.message.clone())
+                            .unwrap_or_else(|| err.user_message());
+                        set_save_status.set(SaveStatus::Failed(message));
+                        return;
+                    }
+                }
+            }
+
+            let Some(id) = id else {
+                return;
+            };
+            let outcome = match action {
+                EditorAction::Save => Ok(()),
+                EditorAction::Publish | EditorAction::Unpublish => api
+                    .set_post_published(id, action == EditorAction::Publish)
+                    .await
+                    .map(|post| is_published.set(post.is_published)),
+            };
+            if post_id.is_disposed() {
+                return;
+            }
+            let status = match outcome {
+                Ok(()) => SaveStatus::Done(action.done_message()),
+                Err(err) => {
+                    log!("Changing publication failed: {}", err);
+                    SaveStatus::Failed(err.user_message())
+                }
+            };
+
+            // Keep the address bar on the post's current slug.
+            let path = format!("/posts/{}/edit", urlencoding::encode(&saved_slug));
+            if location.pathname.get_untracked() != path {
+                let carried = match &status {
+                    SaveStatus::Done(message) => State::new(Some(JsValue::from_str(message))),
+                    SaveStatus::Failed(message) => State::new(Some(JsValue::from_str(message))),
+                    SaveStatus::Idle | SaveStatus::Saving => State::default(),
+                };
+                navigate(
+                    &path,
+                    NavigateOptions {
+                        replace: true,
+                        state: carried,
+                        ..Default::default()
+                    },
+                );
+            }
+            set_save_status.set(status);
+        });
+    });
+
+    let on_drop = move |ev: DragEvent| {
+        is_dragging.set(false);
+        let Some(data) = ev.data_transfer() else {
+            return;
+        };
+        // Plain dragged text keeps the browser's default handling.
+        if data.files().is_some_and(|files| files.length() > 0) {
+            ev.prevent_default();
+            uploader.upload_files(image_files(data.files()));
+        }
+    };
+    let on_paste = move |ev: ClipboardEvent| {
+        let files = image_files(ev.clipboard_data().and_then(|data| data.files()));
+        if !files.is_empty() {
+            ev.prevent_default();
+            uploader.upload_files(files);
+        }
+    };
+    let on_pick = move |ev: leptos::ev::Event| {
+        let Some(input) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+        else {
+            return;
+        };
+        uploader.upload_files(image_files(input.files()));
+        // Allow picking the same file again.
+        input.set_value("");
+    };
+
+    let preview = Memo::new(move |_| body.with(|body| render_markdown(body).html));
+
+    view! {
+        <style>{MARKDOWN_BODY_STYLE}</style>
+        <style>{EDITOR_STYLE}</style>
+        <main class="editor-page">
+            {move || load_error.get().map(|msg| view! { <p class="form-error">{msg}</p> })}
+            <Show when=move || saved_content.with(Option::is_some)>
+                {move || {
+                    pending_draft
+                        .get()
+                        .map(|draft| {
+                            view! {
+                                <div class="draft-banner">
+                                    <span>
+                                        {format!(
+                                            "You have unsaved changes from {}.",
+                                            draft
+                                                .saved_at
+                                                .with_timezone(&Local)
+                                                .format("%Y-%m-%d %H:%M"),
+                                        )}
+                                    </span>
+                                    <button type="button" on:click=restore_draft>
+                                        "Restore"
+                                    </button>
+                                    <button type="button" on:click=discard_draft>
+                                        "Discard"
+                                    </button>
+                                </div>
+                            }
+                        })
+                }}
+                <div class="editor-fields">
+                    <div>
+                        <label for="post_title">"Title*"</label>
+                        <input
+                            id="post_title"
+                            type="text"
+                            prop:value=move || title.value.get()
+                            on:input=move |ev| title.set(event_target_value(&ev))
+                            on:blur=move |_| title.touch()
+                        />
+                        <FieldErrorMessage field=title />
+                    </div>
+                    <div>
+                        <label for="post_slug">"Slug*"</label>
+                        <input
+                            id="post_slug"
+                            type="text"
+                            prop:value=move || slug.value.get()
+                            on:input=move |ev| {
+                                slug_is_custom.set(true);
+                                slug.set(event_target_value(&ev));
+                            }
+                            on:blur=move |_| slug.touch()
+                        />
+                        <FieldErrorMessage field=slug />
+                    </div>
+                    <div>
+                        <label for="post_tags">"Tags (comma-separated)"</label>
+                        <input
+                            id="post_tags"
+                            type="text"
+                            placeholder="rust, wasm"
+                            prop:value=move || tags.value.get()
+                            on:input=move |ev| tags.set(event_target_value(&ev))
+                            on:blur=move |_| tags.touch()
+                        />
+                        <FieldErrorMessage field=tags />
+                    </div>
+                    <div class="editor-excerpt">
+                        <label for="post_excerpt">"Excerpt"</label>
+                        <textarea
+                            id="post_excerpt"
+                            rows="2"
+                            prop:value=move || excerpt.value.get()
+                            on:input=move |ev| excerpt.set(event_target_value(&ev))
+                            on:blur=move |_| excerpt.touch()
+                        ></textarea>
+                        <FieldErrorMessage field=excerpt />
+                    </div>
+                </div>
+                <div class="editor-toolbar">
+                    <label class="editor-upload-button">
+                        "Add images"
+                        <input type="file" accept="image/*" multiple on:change=on_pick />
+                    </label>
+                    <span class="editor-hint">"or drop / paste images into the editor"</span>
+                </div>
+                <UploadProgress uploader=uploader />
+                <div class="editor-panes">
+                    <textarea
+                        class="editor-source"
+                        class:dragging=move || is_dragging.get()
+                        aria-label="Post body (Markdown)"
+                        spellcheck="false"
+                        node_ref=source
+                        prop:value=move || body.get()
+                        on:input=move |ev| body.set(event_target_value(&ev))
+                        on:dragover=move |ev: DragEvent| {
+                            ev.prevent_default();
+                            is_dragging.set(true);
+                        }
+                        on:dragleave=move |_| is_dragging.set(false)
+                        on:drop=on_drop
+                        on:paste=on_paste
+                    ></textarea>
+                    <div class="editor-preview">
+                        <h1>{move || title.value.get()}</h1>
+                        <MarkdownBody html=preview />
+                    </div>
+                </div>
+                <div class="editor-actions">
+                    <button
+                        type="button"
+                        disabled=move || is_busy() || (post_id.get().is_some() && !is_dirty())
+                        on:click=move |_| run.run(EditorAction::Save)
+                    >
+                        "Save"
+                    </button>
+                    {move || {
+                        if is_published.get() {
+                            view! {
+                                <button
+                                    type="button"
+                                    disabled=is_busy
+                                    on:click=move |_| run.run(EditorAction::Unpublish)
+                                >
+                                    "Unpublish"
+                                </button>
+                                <a
+                                    class="editor-view-link"
+                                    href=move || {
+                                        saved_content
+                                            .with(|saved| {
+                                                saved.as_ref().map(|saved| saved.slug.clone())
+                                            })
+                                            .map(|slug| format!("/posts/{}", slug))
+                                            .unwrap_or_default()
+                                    }
+                                >
+                                    "View post"
+                                </a>
+                            }
+                                .into_any()
+                        } else {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="primary"
+                                    disabled=is_busy
+                                    on:click=move |_| run.run(EditorAction::Publish)
+                                >
+                                    "Publish"
+                                </button>
+                            }
+                                .into_any()
+                        }
+                    }}
+                    <span class="editor-status">
+                        {move || match save_status.get() {
+                            SaveStatus::Idle => draft_saved_at
+                                .get()
+                                .map(|saved_at| {
+                                    format!(
+                                        "Draft saved locally at {}.",
+                                        saved_at.with_timezone(&Local).format("%H:%M:%S"),
+                                    )
+                                })
+                                .unwrap_or_default(),
+                            SaveStatus::Saving => "Saving…".to_owned(),
+                            SaveStatus::Done(message) => message.to_owned(),
+                            SaveStatus::Failed(message) => format!("Could not save: {}", message),
+                        }}
+                    </span>
+                </div>
+            </Show>
+        </main>
+    }
+}
@@ -0,0 +1,328 @@
+use std::time::Duration;
+
+use leptos::{html::Textarea, logging::log, prelude::*, task::spawn_local};
+use web_sys::{Blob, File, FileList};
+
+use crate::{api::client::ApiClient, utils::image::downscale_image};
+
+/// Longer side, in pixels, that images are downscaled to before upload.
+const MAX_DIMENSION: u32 = 2000;
+
+/// Largest upload the backend accepts.
+const MAX_UPLOAD_BYTES: f64 = 10.0 * 1024.0 * 1024.0;
+
+/// How long a finished upload stays in the progress list.
+const DONE_LINGER: Duration = Duration::from_secs(3);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UploadStage {
+    Queued,
+    Resizing,
+    Uploading,
+    Done,
+    Failed(String),
+}
+
+#[derive(Clone, Debug)]
+struct Upload {
+    id: u32,
+    name: String,
+    stage: UploadStage,
+}
+
+/// The image files in a drop, paste or file picker selection.
+pub fn image_files(files: Option<FileList>) -> Vec<File> {
+    let Some(files) = files else {
+        return Vec::new();
+    };
+    (0..files.length())
+        .filter_map(|index| files.get(index))
+        .filter(|file| file.type_().starts_with("image/"))
+        .collect()
+}
+
+/// Alt text from a file name: its stem, minus characters that would break
+/// the Markdown image syntax.
+fn alt_text(file_name: &str) -> String {
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem);
+    stem.chars()
+        .filter(|c| !matches!(c, '[' | ']' | '(' | ')'))
+        .collect()
+}
+
+/// Text held in the body while an image uploads; replaced by the real
+/// reference once the backend answers.
+fn placeholder(id: u32, file_name: &str) -> String {
+    format!("![Uploading {}…](#upload-{})", alt_text(file_name), id)
+}
+
+/// `file_name` with its extension matching `mime_type`, for images that were
+/// re-encoded while downscaling.
+fn upload_file_name(file_name: &str, mime_type: &str) -> String {
+    let extension = match mime_type {
+        "image/jpeg" => "jpg",
+        "image/png" => "png",
+        "image/webp" => "webp",
+        _ => return file_name.to_owned(),
+    };
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem);
+    format!("{}.{}", stem, extension)
+}
+
+/// Converts a UTF-16 offset, as used by the DOM selection APIs, into a byte
+/// index into `text`.
+fn byte_index(text: &str, utf16_offset: u32) -> usize {
+    let mut units = 0;
+    for (index, c) in text.char_indices() {
+        if units >= utf16_offset as usize {
+            return index;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+fn utf16_len(text: &str) -> u32 {
+    text.encode_utf16().count() as u32
+}
+
+/// Uploads images dropped or pasted into the editor and splices their
+/// Markdown references into the body.
+#[derive(Clone, Copy)]
+pub struct ImageUploader {
+    api: StoredValue<ApiClient>,
+    body: RwSignal<String>,
+    source: NodeRef<Textarea>,
+    uploads: RwSignal<Vec<Upload>>,
+    next_id: StoredValue<u32>,
+}
+
+impl ImageUploader {
+    pub fn new(api: ApiClient, body: RwSignal<String>, source: NodeRef<Textarea>) -> Self {
+        Self {
+            api: StoredValue::new(api),
+            body,
+            source,
+            uploads: RwSignal::new(Vec::new()),
+            next_id: StoredValue::new(0),
+        }
+    }
+
+    /// Inserts a placeholder per file at the cursor, then uploads the files
+    /// one after another.
+    pub fn upload_files(self, files: Vec<File>) {
+        if files.is_empty() {
+            return;
+        }
+        let queued: Vec<(u32, File)> = files
+            .into_iter()
+            .map(|file| {
+                let id = self.next_id.get_value();
+                self.next_id.set_value(id + 1);
+                self.uploads.update(|uploads| {
+                    uploads.push(Upload {
+                        id,
+                        name: file.name(),
+                        stage: UploadStage::Queued,
+                    })
+                });
+                (id, file)
+            })
+            .collect();
+
+        let placeholders: String = queued
+            .iter()
+            .map(|(id, file)| format!("{}\n", placeholder(*id, &file.name())))
+            .collect();
+        self.insert_at_cursor(&placeholders);
+
+        let api = self.api.get_value();
+        spawn_local(async move {
+            for (id, file) in queued {
+                if self.is_gone() {
+                    break;
+                }
+                self.upload(&api, id, file).await;
+            }
+        });
+    }
+
+    async fn upload(self, api: &ApiClient, id: u32, file: File) {
+        let name = file.name();
+        let placeholder = placeholder(id, &name);
+
+        self.set_stage(id, UploadStage::Resizing);
+        let image: Blob = match downscale_image(&file, MAX_DIMENSION).await {
+            Ok(image) => image,
+            Err(err) => {
+                // Let the backend judge images the browser cannot decode.
+                log!("Downscaling {} failed: {:?}", name, err);
+                file.into()
+            }
+        };
+        if self.is_gone() {
+            return;
+        }
+        if image.size() > MAX_UPLOAD_BYTES {
+            self.replace_text(&placeholder, "");
+            self.set_stage(id, UploadStage::Failed("Larger than 10 MB.".to_owned()));
+            return;
+        }
+
+        self.set_stage(id, UploadStage::Uploading);
+        match api
+            .upload_image(&image, &upload_file_name(&name, &image.type_()))
+            .await
+        {
+            // Nowhere left to put the image.
+            _ if self.is_gone() => {}
+            Ok(uploaded) => {
+                let reference = format!("![{}]({})", alt_text(&name), uploaded.url);
+                // The author may have deleted the placeholder meanwhile.
+                if !self.replace_text(&placeholder, &reference) {
+                    self.insert_at_cursor(&format!("{}\n", reference));
+                }
+                self.set_stage(id, UploadStage::Done);
+                set_timeout(
+                    move || {
+                        self.uploads
+                            .update(|uploads| uploads.retain(|u| u.id != id))
+                    },
+                    DONE_LINGER,
+                );
+            }
+            Err(err) => {
+                log!("Uploading {} failed: {}", name, err);
+                self.replace_text(&placeholder, "");
+                self.set_stage(id, UploadStage::Failed(err.user_message()));
+            }
+        }
+    }
+
+    /// Whether the editor was left while an upload was in flight.
+    fn is_gone(self) -> bool {
+        self.body.is_disposed()
+    }
+
+    fn set_stage(self, id: u32, stage: UploadStage) {
+        self.uploads.update(|uploads| {
+            if let Some(upload) = uploads.iter_mut().find(|upload| upload.id == id) {
+                upload.stage = stage;
+            }
+        });
+    }
+
+    /// The textarea's selection as UTF-16 offsets, if it is mounted.
+    fn selection(self) -> Option<(u32, u32)> {
+        let textarea = self.source.try_get_untracked().flatten()?;
+        let start = textarea.selection_start().ok().flatten()?;
+        let end = textarea.selection_end().ok().flatten()?;
+        Some((start, end))
+    }
+
+    /// Moves the caret once the textarea has picked up the new body.
+    fn set_caret(self, start: u32, end: u32) {
+        let source = self.source;
+        request_animation_frame(move || {
+            if let Some(textarea) = source.try_get_untracked().flatten() {
+                let _ = textarea.set_selection_range(start, end);
+            }
+        });
+    }
+
+    /// Replaces the selection (or appends, without one) with `text` and puts
+    /// the caret after it.
+    fn insert_at_cursor(self, text: &str) {
+        let selection = self.selection();
+        let mut caret = 0;
+        self.body.update(|body| {
+            let (start, end) = match selection {
+                Some((start, end)) => (byte_index(body, start), byte_index(body, end)),
+                None => (body.len(), body.len()),
+            };
+            body.replace_range(start..end, text);
+            caret = utf16_len(&body[..start]) + utf16_len(text);
+        });
+        if selection.is_some() {
+            self.set_caret(caret, caret);
+        }
+    }
+
+    /// Replaces the first occurrence of `from` with `to`, keeping the
+    /// author's selection where it was. Returns whether `from` was found.
+    fn replace_text(self, from: &str, to: &str) -> bool {
+        let selection = self.selection();
+        let mut found = false;
+        let mut shifted = selection;
+        self.body.update(|body| {
+            let Some(at) = body.find(from) else {
+                return;
+            };
+            found = true;
+            let at_utf16 = utf16_len(&body[..at]);
+            let delta = i64::from(utf16_len(to)) - i64::from(utf16_len(from));
+            let shift = |offset: u32| {
+                if offset > at_utf16 {
+                    (i64::from(offset) + delta).max(i64::from(at_utf16)) as u32
+                } else {
+                    offset
+                }
+            };
+            shifted = selection.map(|(start, end)| (shift(start), shift(end)));
+            body.replace_range(at..at + from.len(), to);
+        });
+
+        let focused = self
+            .source
+            .try_get_untracked()
+            .flatten()
+            .is_some_and(|textarea| {
+                document().active_element().as_ref() == Some(textarea.as_ref())
+            });
+        if let (true, Some((start, end))) = (found && focused, shifted) {
+            self.set_caret(start, end);
+        }
+        found
+    }
+}
+
+/// One row per image being uploaded, with its current stage.
+#[component]
+pub fn UploadProgress(uploader: ImageUploader) -> impl IntoView {
+    view! {
+        <ul class="upload-list">
+            {move || {
+                uploader
+                    .uploads
+                    .get()
+                    .into_iter()
+                    .map(|upload| {
+                        let (class, label) = match &upload.stage {
+                            UploadStage::Queued => ("queued", "Waiting…".to_owned()),
+                            UploadStage::Resizing => ("active", "Resizing…".to_owned()),
+                            UploadStage::Uploading => ("active", "Uploading…".to_owned()),
+                            UploadStage::Done => ("done", "Uploaded".to_owned()),
+                            UploadStage::Failed(message) => ("failed", message.clone()),
+                        };
+                        let progress = match upload.stage {
+                            UploadStage::Queued => Some(0.0),
+                            UploadStage::Resizing | UploadStage::Uploading => None,
+                            UploadStage::Done | UploadStage::Failed(_) => Some(1.0),
+                        };
+                        view! {
+                            <li class=format!("upload-item {}", class)>
+                                <span class="upload-name">{upload.name}</span>
+                                <progress max="1" value=progress></progress>
+                                <span class="upload-stage">{label}</span>
+                            </li>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+    }
+}
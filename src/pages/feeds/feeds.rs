@@ -0,0 +1,180 @@
+use chrono::Local;
+use leptos::{logging::log, prelude::*, task::spawn_local};
+use leptos_router::hooks::use_query_map;
+
+use crate::{
+    api::client::ApiClient,
+    components::{
+        document_head::{use_head, PageMeta},
+        subscribe_menu::{FeedUrlList, SUBSCRIBE_STYLE},
+    },
+    utils::feed::{parse_feed, Feed, FeedFormat},
+};
+
+/// The feed URLs and a preview of what a reader gets from each, at
+/// `/feeds?format=`. Doubles as a check of the backend's feed output.
+#[component]
+pub fn Feeds() -> impl IntoView {
+    use_head(|| PageMeta::titled("Subscribe"));
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let query = use_query_map();
+    let format = Memo::new(move |_| {
+        query.with(|query| {
+            query
+                .get_str("format")
+                .and_then(FeedFormat::from_param)
+                .unwrap_or(FeedFormat::Rss)
+        })
+    });
+
+    // `None` while loading; the raw document is kept to show alongside.
+    let (preview, set_preview) = signal(None::<Result<(String, Result<Feed, String>), String>>);
+
+    Effect::new(move |_| {
+        let requested = format.get();
+        set_preview.set(None);
+        let api = api.clone();
+        spawn_local(async move {
+            let result = api
+                .feed(requested)
+                .await
+                .map(|source| {
+                    let feed = parse_feed(requested, &source);
+                    (source, feed)
+                })
+                .map_err(|err| {
+                    log!("Fetching the {} feed failed: {}", requested.label(), err);
+                    err.user_message()
+                });
+            // Another tab was picked, or the page left, while this one loaded.
+            if format.try_get_untracked() != Some(requested) {
+                return;
+            }
+            set_preview.set(Some(result));
+        });
+    });
+
+    view! {
+        <style>{SUBSCRIBE_STYLE}</style>
+        <main class="feed-page">
+            <h1>"Subscribe"</h1>
+            <p>"Paste one of these into your feed reader."</p>
+            <FeedUrlList />
+            <nav class="feed-tabs">
+                {FeedFormat::ALL
+                    .into_iter()
+                    .map(|tab| {
+                        view! {
+                            <a
+                                href=format!("/feeds?format={}", tab.param())
+                                class:active=move || format.get() == tab
+                            >
+                                {tab.label()}
+                            </a>
+                        }
+                    })
+                    .collect_view()}
+            </nav>
+            {move || match preview.get() {
+                None => view! { <p class="feed-message">"Loading feed…"</p> }.into_any(),
+                Some(Err(message)) => view! { <p class="feed-error">{message}</p> }.into_any(),
+                Some(Ok((source, feed))) => {
+                    view! {
+                        {match feed {
+                            Ok(feed) => view! { <FeedPreview feed=feed /> }.into_any(),
+                            Err(message) => view! { <p class="feed-error">{message}</p> }.into_any(),
+                        }}
+                        <details class="feed-source">
+                            <summary>"Raw feed"</summary>
+                            <pre>{source}</pre>
+                        </details>
+                    }
+                        .into_any()
+                }
+            }}
+        </main>
+    }
+}
+
+#[component]
+fn FeedPreview(feed: Feed) -> impl IntoView {
+    view! {
+        {(!feed.warnings.is_empty())
+            .then(|| {
+                view! {
+                    <ul class="feed-warnings">
+                        {feed
+                            .warnings
+                            .into_iter()
+                            .map(|warning| view! { <li>{warning}</li> })
+                            .collect_view()}
+                    </ul>
+                }
+            })}
+        <header class="feed-header">
+            <h2>{feed.title}</h2>
+            {feed
+                .link
+                .map(|link| {
+                    let href = link.clone();
+                    view! { <a href=href>{link}</a> }
+                })}
+            {feed.description.map(|description| view! { <p>{description}</p> })}
+        </header>
+        {if feed.items.is_empty() {
+            view! { <p class="feed-message">"The feed has no items."</p> }.into_any()
+        } else {
+            view! {
+                <ul class="feed-items">
+                    {feed
+                        .items
+                        .into_iter()
+                        .map(|item| {
+                            let title = if item.title.is_empty() {
+                                "(untitled)".to_owned()
+                            } else {
+                                item.title
+                            };
+                            view! {
+                                <li>
+                                    {match item.link {
+                                        Some(link) => {
+                                            view! {
+                                                <a class="feed-item-title" href=link>
+                                                    {title}
+                                                </a>
+                                            }
+                                                .into_any()
+                                        }
+                                        None => {
+                                            view! { <span class="feed-item-title">{title}</span> }
+                                                .into_any()
+                                        }
+                                    }}
+                                    {item
+                                        .published
+                                        .map(|date| {
+                                            view! {
+                                                <p class="feed-item-date">
+                                                    {date
+                                                        .with_timezone(&Local)
+                                                        .format("%Y-%m-%d %H:%M")
+                                                        .to_string()}
+                                                </p>
+                                            }
+                                        })}
+                                    {item
+                                        .summary
+                                        .map(|summary| {
+                                            view! { <p class="feed-item-summary">{summary}</p> }
+                                        })}
+                                </li>
+                            }
+                        })
+                        .collect_view()}
+                </ul>
+            }
+                .into_any()
+        }}
+    }
+}
@@ -1,11 +1,22 @@
 use leptos::prelude::*;
 
+use crate::components::{
+    document_head::{use_head, PageMeta},
+    post_list::{PostFeed, PostList},
+};
+
+/// Lists the latest posts, one page at a time; the page lives in `?page=`.
 #[component]
 pub fn Home() -> impl IntoView {
+    // The site-wide defaults describe the front page.
+    use_head(PageMeta::default);
+
     view! {
-        <main>
-            <h1>"Home Page"</h1>
-            <p>"Welcome to the home page."</p>
+        <main class="post-list">
+            <PostList
+                feed=PostFeed::Latest
+                href=Callback::new(|page: u32| format!("/?page={}", page))
+            />
         </main>
     }
 }
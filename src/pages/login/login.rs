@@ -1,31 +1,26 @@
 use leptos::{logging::log, prelude::*, task::spawn_local};
-use leptos_router::hooks::use_navigate;
-use uuid::Uuid;
-use web_sys::{wasm_bindgen::JsCast, RequestCredentials};
+use leptos_router::hooks::{use_navigate, use_query_map};
 
-use crate::{dto::api_response::ResponseFormat, GlobalAppState};
-
-/// Request sent to the backend for login.
-#[derive(serde_derive::Deserialize, serde_derive::Serialize, Clone, Debug)]
-pub struct LoginRequest {
-    pub user_email: String,
-    pub user_password: String,
-}
-
-/// Expected login response from the backend.
-#[derive(Clone, serde_derive::Deserialize, Debug)]
-pub struct LoginResponse {
-    pub message: String,
-    pub user_id: Uuid,
-}
+use crate::{
+    api::client::ApiClient,
+    components::{
+        document_head::{use_head, PageMeta},
+        field_error::FieldErrorMessage,
+        route_guards::next_path,
+    },
+    dto::auth::LoginRequest,
+    utils::validation::{all_valid, apply_field_errors, Field, Rule},
+    GlobalAppState,
+};
 
 #[component]
 pub fn Login() -> impl IntoView {
-    // Build up the login form’s state.
-    let (login_state, set_login_state) = signal(LoginRequest {
-        user_email: String::new(),
-        user_password: String::new(),
-    });
+    use_head(|| PageMeta::titled("Log in"));
+    // Build up the login form’s state. Only syntax is checked here; the
+    // password rules of the day may be stricter than when it was set.
+    let user_email = Field::new(vec![Rule::Required, Rule::Email]);
+    let user_password = Field::new(vec![Rule::Required]);
+    let is_form_valid = move || all_valid(&[user_email, user_password]);
 
     // Retrieve a readable and writeable global state from context.
     let global_state =
@@ -33,89 +28,53 @@ pub fn Login() -> impl IntoView {
     let global_state_set =
         use_context::<WriteSignal<GlobalAppState>>().expect("global_state setter not provided");
 
-    // Get backend connection details from global state.
-    let state_value = global_state.get_untracked();
-    let backend_url = std::rc::Rc::new(state_value.backend_url.clone());
-    let api_key = std::rc::Rc::new(state_value.api_key.clone());
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    // Error reported by the backend for the last submission, shown above the form.
+    let (error_message, set_error_message) = signal(None::<String>);
 
     let navigate = use_navigate();
-
-    // Update email in the login state.
-    let on_email_input = {
-        let set_login_state = set_login_state.clone();
-        move |ev: web_sys::Event| {
-            if let Some(input) = ev
-                .target()
-                .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
-            {
-                let email = input.value();
-                set_login_state.update(|state| state.user_email = email);
-            }
-        }
-    };
-
-    // Update password in the login state.
-    let on_password_input = {
-        let set_login_state = set_login_state.clone();
-        move |ev: web_sys::Event| {
-            if let Some(input) = ev
-                .target()
-                .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
-            {
-                let pwd = input.value();
-                set_login_state.update(|state| state.user_password = pwd);
-            }
-        }
-    };
+    // Where a route guard wanted to send the user before bouncing them here.
+    let query = use_query_map();
+    // Set by ResetPassword when it sends the user back here.
+    let was_reset = query.get_untracked().get("reset").as_deref() == Some("success");
 
     // Handle form submission.
-    let on_submit = {
-        let login_state = login_state.clone();
-        let backend_url = backend_url.clone();
-        let api_key = api_key.clone();
-        let global_state_set = global_state_set.clone();
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if !is_form_valid() {
+            return;
+        }
+        set_error_message.set(None);
+        let login_data = LoginRequest {
+            user_email: user_email.value.get(),
+            user_password: user_password.value.get(),
+        };
+        let api = api.clone();
         let navigate = navigate.clone();
-        move |ev: leptos::ev::SubmitEvent| {
-            ev.prevent_default();
-            let login_data = login_state.get();
-            let backend_url = backend_url.clone();
-            let api_key = api_key.clone();
-            let global_state_set = global_state_set.clone();
-            let navigate = navigate.clone();
-            spawn_local(async move {
-                let url = format!("{}/auth/login", backend_url.as_ref());
-                let req = gloo_net::http::Request::post(&url)
-                    .credentials(RequestCredentials::Include)
-                    .header("x-api-key", api_key.as_ref())
-                    .header("Content-Type", "application/json")
-                    .body(serde_json::to_string(&login_data).unwrap())
-                    .unwrap();
+        spawn_local(async move {
+            match api.login(&login_data).await {
+                Ok(resp) => {
+                    // Update global state with the received user_id and email.
+                    global_state_set
+                        .update(|state| state.sign_in(resp.user_id, login_data.user_email.clone()));
+                    log!("Login successful: {:?}", global_state.get_untracked());
 
-                match req.send().await {
-                    Ok(response) => match response.json::<ResponseFormat<LoginResponse>>().await {
-                        Ok(resp) => {
-                            if resp.success {
-                                // Update global state with the received user_id and email.
-                                global_state_set.update(|state| {
-                                    state.user_id = Some(resp.data.user_id);
-                                    state.email = Some(login_data.user_email.clone());
-                                    state.is_logged_in = true;
-                                });
-                                // The create_effect in App (in main.rs) will persist these changes.
-                                log!("Login successful: {:?}", global_state.get());
-
-                                // Navigate to the home page after login.
-                                navigate("/", Default::default());
-                            } else {
-                                log!("Login failed at backend: {:?}", resp);
-                            }
-                        }
-                        Err(err) => log!("Error parsing login response JSON: {:?}", err),
-                    },
-                    Err(err) => log!("Error sending login request: {:?}", err),
+                    // Resume wherever the user was headed, or go home.
+                    let next = next_path(query.get_untracked().get("next"));
+                    navigate(&next, Default::default());
                 }
-            });
-        }
+                Err(err) => {
+                    log!("Login failed: {}", err);
+                    let unmatched = apply_field_errors(
+                        &[("user_email", user_email), ("user_password", user_password)],
+                        err.field_errors(),
+                    );
+                    if err.field_errors().is_empty() || !unmatched.is_empty() {
+                        set_error_message.set(Some(err.user_message()));
+                    }
+                }
+            }
+        });
     };
 
     view! {
@@ -124,6 +83,14 @@ pub fn Login() -> impl IntoView {
             <div class="container">
                 <div class="login-form">
                     <h2>"Log In"</h2>
+                    <Show when=move || was_reset>
+                        <p class="form-success">
+                            "Your password has been reset. Log in with your new password."
+                        </p>
+                    </Show>
+                    {move || {
+                        error_message.get().map(|msg| view! { <p class="form-error">{msg}</p> })
+                    }}
                     <form on:submit=on_submit>
                         <div>
                             <label for="user_email">"Email*:"</label>
@@ -131,8 +98,10 @@ pub fn Login() -> impl IntoView {
                                 id="user_email"
                                 type="email"
                                 placeholder="Your Email"
-                                on:input=on_email_input
+                                on:input=move |ev| user_email.set(event_target_value(&ev))
+                                on:blur=move |_| user_email.touch()
                             />
+                            <FieldErrorMessage field=user_email />
                         </div>
                         <div>
                             <label for="user_password">"Password*:"</label>
@@ -140,11 +109,18 @@ pub fn Login() -> impl IntoView {
                                 id="user_password"
                                 type="password"
                                 placeholder="Your Password"
-                                on:input=on_password_input
+                                on:input=move |ev| user_password.set(event_target_value(&ev))
+                                on:blur=move |_| user_password.touch()
                             />
+                            <FieldErrorMessage field=user_password />
                         </div>
-                        <button type="submit">"Log In"</button>
+                        <button type="submit" disabled=move || !is_form_valid()>
+                            "Log In"
+                        </button>
                     </form>
+                    <a class="forgot-password" href="/account/forgot-password">
+                        "Forgot password?"
+                    </a>
                 </div>
             </div>
         </>
@@ -0,0 +1,119 @@
+use chrono::Utc;
+use leptos::{logging::log, prelude::*, task::spawn_local};
+
+use crate::{
+    api::{client::ApiClient, error::ApiError},
+    components::{
+        document_head::{use_head, PageMeta},
+        field_error::FieldErrorMessage,
+    },
+    dto::auth::ForgotPasswordRequest,
+    pages::signup::signup::SIGNUP_STYLE,
+    utils::{
+        time::{format_remaining, use_clock},
+        validation::{email_rules, Field},
+    },
+};
+
+/// How long the user has to wait between two reset requests.
+const REQUEST_COOLDOWN: chrono::Duration = chrono::Duration::seconds(60);
+
+#[component]
+pub fn ForgotPassword() -> impl IntoView {
+    use_head(|| PageMeta::titled("Forgot password"));
+    let api = use_context::<ApiClient>().expect("api client not provided");
+
+    let user_email = Field::new(email_rules());
+    let now = use_clock();
+    let next_request_at = RwSignal::new(None::<chrono::DateTime<Utc>>);
+    let cooldown = move || {
+        next_request_at
+            .get()
+            .and_then(|at| format_remaining(at, now.get()))
+    };
+
+    let (is_sending, set_is_sending) = signal(false);
+    let (message, set_message) = signal(None::<String>);
+    let (error_message, set_error_message) = signal(None::<String>);
+
+    let can_submit = move || !is_sending.get() && cooldown().is_none() && user_email.is_valid();
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if !can_submit() {
+            return;
+        }
+        let email = user_email.value.get();
+        let request = ForgotPasswordRequest {
+            user_email: email.clone(),
+        };
+        let api = api.clone();
+        set_is_sending.set(true);
+        set_message.set(None);
+        set_error_message.set(None);
+        spawn_local(async move {
+            match api.forgot_password(&request).await {
+                // Checked first: the backend may report rate limits in its
+                // error envelope, which would otherwise pass for success below.
+                Err(err) if err.status() == Some(429) => {
+                    set_error_message
+                        .set(Some("Too many requests. Please wait a moment.".to_owned()));
+                }
+                // Business errors (e.g. unknown email) get the same answer as
+                // success so the form can't be used to probe for accounts.
+                Ok(()) | Err(ApiError::Backend { .. }) => {
+                    set_message.set(Some(format!(
+                        "If an account exists for {}, a password reset link is on its way.",
+                        email
+                    )));
+                }
+                Err(err) => {
+                    log!("Requesting password reset failed: {}", err);
+                    set_error_message.set(Some(err.user_message()));
+                }
+            }
+            next_request_at.set(Some(Utc::now() + REQUEST_COOLDOWN));
+            set_is_sending.set(false);
+        });
+    };
+
+    view! {
+        <>
+            <style>{SIGNUP_STYLE}</style>
+            <div class="container">
+                <div class="signup-form">
+                    <h2>"Forgot Password"</h2>
+                    <p>"Enter your email and we'll send you a link to reset your password."</p>
+                    {move || {
+                        error_message.get().map(|msg| view! { <p class="form-error">{msg}</p> })
+                    }}
+                    <form on:submit=on_submit>
+                        <div>
+                            <label for="user_email">"Email*:"</label>
+                            <input
+                                id="user_email"
+                                type="email"
+                                placeholder="Your Email"
+                                on:input=move |ev| user_email.set(event_target_value(&ev))
+                                on:blur=move |_| user_email.touch()
+                            />
+                            <FieldErrorMessage field=user_email />
+                        </div>
+                        <button type="submit" disabled=move || !can_submit()>
+                            {move || match cooldown() {
+                                Some(remaining) => format!("Send reset link (in {})", remaining),
+                                None => "Send reset link".to_owned(),
+                            }}
+                        </button>
+                    </form>
+                    {move || message.get().map(|msg| view! { <p>{msg}</p> })}
+                    <p>
+                        <a href="/account/login" style="color: #ccc;">
+                            "Back to Log In"
+                        </a>
+                    </p>
+                </div>
+            </div>
+        </>
+    }
+}
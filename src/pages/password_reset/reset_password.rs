@@ -0,0 +1,116 @@
+use leptos::{logging::log, prelude::*, task::spawn_local};
+use leptos_router::hooks::{use_navigate, use_query_map};
+
+use crate::{
+    api::{client::ApiClient, error::error_codes},
+    components::{
+        document_head::{use_head, PageMeta},
+        new_password::NewPasswordFields,
+    },
+    dto::auth::ResetPasswordRequest,
+    pages::signup::signup::SIGNUP_STYLE,
+    utils::{
+        password_strength::use_password_strength,
+        validation::{all_valid, apply_field_errors, new_password_rules, Field, Rule},
+    },
+};
+
+#[component]
+pub fn ResetPassword() -> impl IntoView {
+    use_head(|| PageMeta::titled("Reset password"));
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let navigate = use_navigate();
+    let token = use_query_map().get_untracked().get("token");
+
+    let new_password = Field::new(new_password_rules());
+    let new_password_confirm = Field::new(vec![Rule::Required, Rule::Matches(new_password.value)]);
+    let password_strength = use_password_strength(new_password, Vec::new);
+
+    let (error_message, set_error_message) = signal(None::<String>);
+    // Expired or unknown token: the only way forward is a new link.
+    let (is_token_dead, set_is_token_dead) = signal(token.is_none());
+    let (is_sending, set_is_sending) = signal(false);
+
+    let can_submit = move || {
+        !is_sending.get()
+            && !is_token_dead.get()
+            && all_valid(&[new_password, new_password_confirm])
+            && password_strength.get().is_acceptable()
+    };
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let Some(token) = token.clone() else {
+            return;
+        };
+        if !can_submit() {
+            return;
+        }
+        let request = ResetPasswordRequest {
+            password_reset_token: token,
+            new_password: new_password.value.get(),
+        };
+        let api = api.clone();
+        let navigate = navigate.clone();
+        set_is_sending.set(true);
+        set_error_message.set(None);
+        spawn_local(async move {
+            match api.reset_password(&request).await {
+                Ok(()) => navigate("/account/login?reset=success", Default::default()),
+                Err(err) => {
+                    log!("Resetting password failed: {}", err);
+                    if matches!(
+                        err.error_code(),
+                        Some(error_codes::TOKEN_EXPIRED) | Some(error_codes::TOKEN_INVALID)
+                    ) {
+                        set_is_token_dead.set(true);
+                    }
+                    let unmatched =
+                        apply_field_errors(&[("new_password", new_password)], err.field_errors());
+                    if err.field_errors().is_empty() || !unmatched.is_empty() {
+                        set_error_message.set(Some(err.user_message()));
+                    }
+                }
+            }
+            set_is_sending.set(false);
+        });
+    };
+
+    view! {
+        <>
+            <style>{SIGNUP_STYLE}</style>
+            <div class="container">
+                <div class="signup-form">
+                    <h2>"Reset Password"</h2>
+                    {move || {
+                        error_message.get().map(|msg| view! { <p class="form-error">{msg}</p> })
+                    }}
+                    <Show
+                        when=move || !is_token_dead.get()
+                        fallback=|| {
+                            view! {
+                                <p>"This reset link is invalid or has expired."</p>
+                                <a href="/account/forgot-password" style="color: #ccc;">
+                                    "Request a new link"
+                                </a>
+                            }
+                        }
+                    >
+                        <form on:submit=on_submit.clone()>
+                            <NewPasswordFields
+                                password=new_password
+                                confirm=new_password_confirm
+                                strength=password_strength
+                                id="new_password"
+                                label="New Password"
+                            />
+                            <button type="submit" disabled=move || !can_submit()>
+                                "Reset Password"
+                            </button>
+                        </form>
+                    </Show>
+                </div>
+            </div>
+        </>
+    }
+}
@@ -0,0 +1,160 @@
+use chrono::Local;
+use leptos::{logging::log, prelude::*, task::spawn_local};
+use leptos_router::hooks::use_params_map;
+
+use crate::{
+    api::client::ApiClient,
+    components::{
+        comments::CommentThread,
+        document_head::{use_head, ArticleMeta, PageMeta},
+        markdown_body::{MarkdownBody, MARKDOWN_BODY_STYLE},
+        post_card::{TagList, POST_CARD_STYLE},
+    },
+    dto::post::Post,
+    utils::markdown::{render_markdown, RenderedMarkdown, TocEntry},
+};
+
+const POST_STYLE: &str = include_str!("./post.css");
+
+/// A single post at `/posts/:slug`, rendered from Markdown with a table of
+/// contents linking to its headings.
+#[component]
+pub fn PostDetail() -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let params = use_params_map();
+    let slug = Memo::new(move |_| params.with(|params| params.get("slug").unwrap_or_default()));
+
+    // `None` while the post is loading.
+    let (post, set_post) = signal(None::<Result<(Post, RenderedMarkdown), String>>);
+
+    use_head(move || {
+        post.with(|post| match post {
+            Some(Ok((post, _))) => PageMeta {
+                title: Some(post.title.clone()),
+                description: Some(post.excerpt.clone()).filter(|excerpt| !excerpt.is_empty()),
+                canonical_path: Some(format!("/posts/{}", urlencoding::encode(&post.slug))),
+                image: None,
+                article: Some(ArticleMeta {
+                    published_at: post.published_at,
+                    modified_at: post.updated_at,
+                    tags: post.tags.clone(),
+                }),
+            },
+            _ => PageMeta::default(),
+        })
+    });
+
+    Effect::new(move |_| {
+        let requested = slug.get();
+        let api = api.clone();
+        set_post.set(None);
+        spawn_local(async move {
+            let result = api.post_by_slug(&requested).await;
+            // Drop responses for a post the user has already moved away from,
+            // including by leaving the page altogether.
+            if slug.try_get_untracked().as_ref() != Some(&requested) {
+                return;
+            }
+            set_post.set(Some(match result {
+                Ok(post) => {
+                    let rendered = render_markdown(&post.body_markdown);
+                    Ok((post, rendered))
+                }
+                Err(err) if err.status() == Some(404) => Err("Post not found.".to_owned()),
+                Err(err) => {
+                    log!("Fetching post failed: {}", err);
+                    Err(err.user_message())
+                }
+            }));
+        });
+    });
+
+    view! {
+        <style>{POST_CARD_STYLE}</style>
+        <style>{MARKDOWN_BODY_STYLE}</style>
+        <style>{POST_STYLE}</style>
+        <main class="post-page">
+            {move || match post.get() {
+                None => view! { <p class="post-message">"Loading…"</p> }.into_any(),
+                Some(Err(message)) => view! { <p class="post-message">{message}</p> }.into_any(),
+                Some(Ok((post, rendered))) => {
+                    view! { <PostArticle post=post rendered=rendered /> }.into_any()
+                }
+            }}
+        </main>
+    }
+}
+
+#[component]
+fn PostArticle(post: Post, rendered: RenderedMarkdown) -> impl IntoView {
+    let published = post
+        .published_at
+        .with_timezone(&Local)
+        .format("%Y-%m-%d")
+        .to_string();
+    let updated = post
+        .updated_at
+        .filter(|updated_at| *updated_at > post.published_at)
+        .map(|updated_at| {
+            format!(
+                " · updated {}",
+                updated_at.with_timezone(&Local).format("%Y-%m-%d")
+            )
+        })
+        .unwrap_or_default();
+    let RenderedMarkdown { html, toc } = rendered;
+
+    view! {
+        <article class="post">
+            <header class="post-header">
+                <h1 class="post-title">{post.title}</h1>
+                <p class="post-meta">
+                    {format!(
+                        "{} · {}{} · {} min read",
+                        post.author_name,
+                        published,
+                        updated,
+                        post.read_time_minutes,
+                    )}
+                </p>
+                <TagList tags=post.tags />
+            </header>
+            <TableOfContents entries=toc />
+            <MarkdownBody html=html />
+            <CommentThread post_id=post.post_id />
+        </article>
+    }
+}
+
+/// Links to the post's headings, indented by level. Hidden for posts too
+/// short to need one.
+#[component]
+fn TableOfContents(entries: Vec<TocEntry>) -> impl IntoView {
+    if entries.len() < 2 {
+        return ().into_any();
+    }
+    let top_level = entries.iter().map(|entry| entry.level).min().unwrap_or(1);
+
+    view! {
+        <nav class="post-toc" aria-label="Table of contents">
+            <p class="post-toc-title">"Contents"</p>
+            <ul>
+                {entries
+                    .into_iter()
+                    .map(|entry| {
+                        let indent = format!(
+                            "padding-left: {}em;",
+                            (entry.level - top_level) as f32 * 1.2,
+                        );
+                        view! {
+                            <li style=indent>
+                                <a href=format!("#{}", entry.id)>{entry.title}</a>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ul>
+        </nav>
+    }
+    .into_any()
+}
@@ -0,0 +1,192 @@
+use chrono::Local;
+use leptos::{logging::log, prelude::*, task::spawn_local};
+use leptos_router::hooks::{use_navigate, use_query_map};
+
+use crate::{
+    api::client::ApiClient,
+    components::{
+        document_head::{use_head, PageMeta},
+        pagination::{use_page_param, Pagination},
+        post_card::{TagList, POST_CARD_STYLE},
+        search_box::{Highlighted, MIN_QUERY_LENGTH, SEARCH_STYLE},
+    },
+    dto::search::{SearchHit, SearchPage},
+    utils::search::query_terms,
+};
+
+const PAGE_SIZE: u32 = 10;
+
+/// Full search results at `/search?q=`, one page at a time.
+#[component]
+pub fn Search() -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let navigate = use_navigate();
+    let query_map = use_query_map();
+    let query = Memo::new(move |_| {
+        query_map.with(|query| query.get("q").unwrap_or_default().trim().to_owned())
+    });
+    let page = use_page_param();
+
+    use_head(move || {
+        query.with(|query| {
+            if query.is_empty() {
+                PageMeta::titled("Search")
+            } else {
+                PageMeta::titled(format!("Search: {}", query))
+            }
+        })
+    });
+
+    // Follows the URL, e.g. when searching again from the top bar.
+    let input = RwSignal::new(String::new());
+    Effect::new(move |_| input.set(query.get()));
+
+    // `None` while loading.
+    let (results, set_results) = signal(None::<Result<SearchPage, String>>);
+
+    Effect::new(move |_| {
+        let requested = (query.get(), page.get());
+        set_results.set(None);
+        if requested.0.chars().count() < MIN_QUERY_LENGTH {
+            return;
+        }
+        let api = api.clone();
+        spawn_local(async move {
+            let (requested_query, requested_page) = &requested;
+            let result = api
+                .search_posts(requested_query, *requested_page, PAGE_SIZE, None)
+                .await;
+            // Drop responses for a search the user has already moved away from.
+            // Also covers leaving /search mid-request, which disposes both.
+            let current = query.try_get_untracked().zip(page.try_get_untracked());
+            if current.as_ref() != Some(&requested) {
+                return;
+            }
+            set_results.set(Some(result.map_err(|err| {
+                log!("Searching failed: {}", err);
+                err.user_message()
+            })));
+        });
+    });
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let text = input.get_untracked().trim().to_owned();
+        navigate(
+            &format!("/search?q={}", urlencoding::encode(&text)),
+            Default::default(),
+        );
+    };
+
+    let total_pages = move || {
+        results.with(|results| match results {
+            Some(Ok(results)) => results.total_pages,
+            _ => 0,
+        })
+    };
+
+    view! {
+        <style>{POST_CARD_STYLE}</style>
+        <style>{SEARCH_STYLE}</style>
+        <main class="post-list">
+            <form class="search-page-form" on:submit=on_submit>
+                <input
+                    type="search"
+                    placeholder="Search posts…"
+                    aria-label="Search posts"
+                    prop:value=move || input.get()
+                    on:input=move |ev| input.set(event_target_value(&ev))
+                />
+                <button type="submit">"Search"</button>
+            </form>
+            {move || {
+                if query.with(|query| query.chars().count() < MIN_QUERY_LENGTH) {
+                    return view! {
+                        <p class="list-message">
+                            {format!("Type at least {} characters to search.", MIN_QUERY_LENGTH)}
+                        </p>
+                    }
+                        .into_any();
+                }
+                let terms = query_terms(&query.get());
+                match results.get() {
+                    None => view! { <p class="list-message">"Searching…"</p> }.into_any(),
+                    Some(Err(message)) => {
+                        view! { <p class="list-message">{message}</p> }.into_any()
+                    }
+                    Some(Ok(results)) if results.hits.is_empty() => {
+                        view! {
+                            <p class="list-message">
+                                {format!("No posts match “{}”.", query.get())}
+                            </p>
+                        }
+                            .into_any()
+                    }
+                    Some(Ok(results)) => {
+                        view! {
+                            <p class="search-summary">
+                                {format!(
+                                    "{} result{} for “{}”",
+                                    results.total_hits,
+                                    if results.total_hits == 1 { "" } else { "s" },
+                                    query.get(),
+                                )}
+                            </p>
+                            {results
+                                .hits
+                                .into_iter()
+                                .map(|hit| view! { <SearchResult hit=hit terms=terms.clone() /> })
+                                .collect_view()}
+                        }
+                            .into_any()
+                    }
+                }
+            }}
+            <Pagination
+                page=page
+                total_pages=Signal::derive(total_pages)
+                href=Callback::new(move |page: u32| {
+                    format!(
+                        "/search?q={}&page={}",
+                        urlencoding::encode(&query.get_untracked()),
+                        page,
+                    )
+                })
+            />
+        </main>
+    }
+}
+
+/// A post card with the query's terms highlighted in its title and snippet.
+#[component]
+fn SearchResult(hit: SearchHit, terms: Vec<String>) -> impl IntoView {
+    let post = hit.post;
+    let href = format!("/posts/{}", post.slug);
+    let published = post
+        .published_at
+        .with_timezone(&Local)
+        .format("%Y-%m-%d")
+        .to_string();
+
+    view! {
+        <article class="post-card">
+            <h2 class="post-card-title">
+                <a href=href>
+                    <Highlighted text=post.title terms=terms.clone() />
+                </a>
+            </h2>
+            <p class="post-card-meta">
+                {format!(
+                    "{} · {} · {} min read",
+                    post.author_name,
+                    published,
+                    post.read_time_minutes,
+                )}
+            </p>
+            <p class="post-card-excerpt">
+                <Highlighted text=hit.snippet terms=terms />
+            </p>
+            <TagList tags=post.tags />
+        </article>
+    }
+}
@@ -0,0 +1,136 @@
+use leptos::{logging::log, prelude::*, task::spawn_local};
+
+use crate::{
+    api::{client::ApiClient, error::error_codes, session::revive_session},
+    components::{
+        document_head::{use_head, PageMeta},
+        field_error::FieldErrorMessage,
+        reauth_gate::{expire_reauth, ReauthGate},
+    },
+    dto::auth::ChangeEmailRequest,
+    pages::signup::signup::SIGNUP_STYLE,
+    utils::validation::{apply_field_errors, email_rules, Field},
+    GlobalAppState,
+};
+
+#[component]
+pub fn ChangeEmail() -> impl IntoView {
+    use_head(|| PageMeta::titled("Change email"));
+    view! {
+        <>
+            <style>{SIGNUP_STYLE}</style>
+            <div class="container">
+                <div class="signup-form">
+                    <h2>"Change Email"</h2>
+                    <ReauthGate>
+                        <ChangeEmailForm />
+                    </ReauthGate>
+                </div>
+            </div>
+        </>
+    }
+}
+
+#[component]
+fn ChangeEmailForm() -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let global_state =
+        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
+    let set_global_state =
+        use_context::<WriteSignal<GlobalAppState>>().expect("global_state setter not provided");
+
+    let new_email = Field::new(email_rules());
+    let is_same_as_current =
+        move || global_state.with(|state| state.email.as_deref() == Some(&new_email.value.get()));
+
+    let (error_message, set_error_message) = signal(None::<String>);
+    let (is_sending, set_is_sending) = signal(false);
+    // The address the verification link went to, once the change is requested.
+    let (sent_to, set_sent_to) = signal(None::<String>);
+
+    let can_submit = move || !is_sending.get() && new_email.is_valid() && !is_same_as_current();
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if !can_submit() {
+            return;
+        }
+        let request = ChangeEmailRequest {
+            new_email: new_email.value.get(),
+        };
+        let api = api.clone();
+        set_is_sending.set(true);
+        set_error_message.set(None);
+        spawn_local(async move {
+            match api.change_email(&request).await {
+                Ok(resp) => {
+                    // The new address only takes effect once verified through
+                    // ValidateEmail, but refresh now in case the backend changed anything.
+                    revive_session(&api, set_global_state).await;
+                    set_sent_to.set(Some(resp.new_email));
+                }
+                Err(err) => {
+                    log!("Changing email failed: {}", err);
+                    if err.error_code() == Some(error_codes::REAUTH_REQUIRED) {
+                        expire_reauth(set_global_state);
+                    }
+                    let unmatched =
+                        apply_field_errors(&[("new_email", new_email)], err.field_errors());
+                    if err.field_errors().is_empty() || !unmatched.is_empty() {
+                        set_error_message.set(Some(err.user_message()));
+                    }
+                }
+            }
+            set_is_sending.set(false);
+        });
+    };
+
+    view! {
+        {move || match sent_to.get() {
+            Some(email) => {
+                view! {
+                    <p>
+                        {format!(
+                            "We sent a verification link to {}. Your email changes once you open it.",
+                            email,
+                        )}
+                    </p>
+                    <a href="/account/settings">"Back to Account Settings"</a>
+                }
+                    .into_any()
+            }
+            None => {
+                view! {
+                    {move || {
+                        error_message.get().map(|msg| view! { <p class="form-error">{msg}</p> })
+                    }}
+                    <form on:submit=on_submit.clone()>
+                        <p>
+                            {move || {
+                                format!(
+                                    "Current email: {}",
+                                    global_state.with(|state| state.email.clone().unwrap_or_default()),
+                                )
+                            }}
+                        </p>
+                        <div>
+                            <label for="new_email">"New Email*:"</label>
+                            <input
+                                id="new_email"
+                                type="email"
+                                placeholder="Your New Email"
+                                on:input=move |ev| new_email.set(event_target_value(&ev))
+                                on:blur=move |_| new_email.touch()
+                            />
+                            <FieldErrorMessage field=new_email />
+                        </div>
+                        <button type="submit" disabled=move || !can_submit()>
+                            "Send Verification Link"
+                        </button>
+                    </form>
+                }
+                    .into_any()
+            }
+        }}
+    }
+}
@@ -0,0 +1,139 @@
+use leptos::{logging::log, prelude::*, task::spawn_local};
+
+use crate::{
+    api::{client::ApiClient, session::revive_session},
+    components::{
+        document_head::{use_head, PageMeta},
+        field_error::FieldErrorMessage,
+        new_password::NewPasswordFields,
+    },
+    dto::auth::{ChangePasswordRequest, ReauthenticateRequest},
+    pages::signup::signup::SIGNUP_STYLE,
+    utils::{
+        password_strength::use_password_strength,
+        validation::{all_valid, apply_field_errors, new_password_rules, Field, Rule},
+    },
+    GlobalAppState,
+};
+
+#[component]
+pub fn ChangePassword() -> impl IntoView {
+    use_head(|| PageMeta::titled("Change password"));
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let global_state =
+        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
+    let set_global_state =
+        use_context::<WriteSignal<GlobalAppState>>().expect("global_state setter not provided");
+
+    // The current password doubles as the re-authentication for this change.
+    let current_password = Field::new(vec![Rule::Required]);
+    let new_password = Field::new(new_password_rules());
+    let new_password_confirm = Field::new(vec![Rule::Required, Rule::Matches(new_password.value)]);
+    let password_strength = use_password_strength(new_password, move || {
+        let email = global_state.with(|state| state.email.clone().unwrap_or_default());
+        vec![email.split('@').next().unwrap_or_default().to_owned()]
+    });
+
+    let (error_message, set_error_message) = signal(None::<String>);
+    let (is_sending, set_is_sending) = signal(false);
+    let (is_done, set_is_done) = signal(false);
+
+    let can_submit = move || {
+        !is_sending.get()
+            && all_valid(&[current_password, new_password, new_password_confirm])
+            && password_strength.get().is_acceptable()
+    };
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if !can_submit() {
+            return;
+        }
+        let reauth = ReauthenticateRequest {
+            user_password: current_password.value.get(),
+        };
+        let request = ChangePasswordRequest {
+            new_password: new_password.value.get(),
+        };
+        let api = api.clone();
+        set_is_sending.set(true);
+        set_error_message.set(None);
+        spawn_local(async move {
+            let result = match api.reauthenticate(&reauth).await {
+                Ok(resp) => {
+                    set_global_state
+                        .update(|state| state.reauthenticated_until = Some(resp.valid_until));
+                    api.change_password(&request).await
+                }
+                Err(err) => {
+                    current_password.reset(String::new());
+                    Err(err)
+                }
+            };
+            match result {
+                Ok(()) => {
+                    // The backend may have rotated the session; pick up whatever it says now.
+                    revive_session(&api, set_global_state).await;
+                    set_is_done.set(true);
+                }
+                Err(err) => {
+                    log!("Changing password failed: {}", err);
+                    let unmatched =
+                        apply_field_errors(&[("new_password", new_password)], err.field_errors());
+                    if err.field_errors().is_empty() || !unmatched.is_empty() {
+                        set_error_message.set(Some(err.user_message()));
+                    }
+                }
+            }
+            set_is_sending.set(false);
+        });
+    };
+
+    view! {
+        <>
+            <style>{SIGNUP_STYLE}</style>
+            <div class="container">
+                <div class="signup-form">
+                    <h2>"Change Password"</h2>
+                    <Show
+                        when=move || !is_done.get()
+                        fallback=|| {
+                            view! {
+                                <p>"Your password has been changed."</p>
+                                <a href="/account/settings">"Back to Account Settings"</a>
+                            }
+                        }
+                    >
+                        {move || {
+                            error_message.get().map(|msg| view! { <p class="form-error">{msg}</p> })
+                        }}
+                        <form on:submit=on_submit.clone()>
+                            <div>
+                                <label for="current_password">"Current Password*:"</label>
+                                <input
+                                    id="current_password"
+                                    type="password"
+                                    placeholder="Your Current Password"
+                                    prop:value=move || current_password.value.get()
+                                    on:input=move |ev| current_password.set(event_target_value(&ev))
+                                    on:blur=move |_| current_password.touch()
+                                />
+                                <FieldErrorMessage field=current_password />
+                            </div>
+                            <NewPasswordFields
+                                password=new_password
+                                confirm=new_password_confirm
+                                strength=password_strength
+                                id="new_password"
+                                label="New Password"
+                            />
+                            <button type="submit" disabled=move || !can_submit()>
+                                "Change Password"
+                            </button>
+                        </form>
+                    </Show>
+                </div>
+            </div>
+        </>
+    }
+}
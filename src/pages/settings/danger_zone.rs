@@ -0,0 +1,114 @@
+use leptos::{logging::log, prelude::*, task::spawn_local};
+use leptos_router::hooks::use_navigate;
+
+use crate::{
+    api::{client::ApiClient, session::end_session},
+    utils::download::download_text,
+    GlobalAppState,
+};
+
+/// What the user has to type before the delete button unlocks.
+const DELETE_CONFIRMATION: &str = "delete my account";
+
+/// Self-service data export and account deletion, shown at the bottom of Settings.
+#[component]
+pub fn DangerZone() -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let set_global_state =
+        use_context::<WriteSignal<GlobalAppState>>().expect("global_state setter not provided");
+    let navigate = use_navigate();
+
+    let (is_exporting, set_is_exporting) = signal(false);
+    let (export_error, set_export_error) = signal(None::<String>);
+
+    let (confirmation, set_confirmation) = signal(String::new());
+    let (is_deleting, set_is_deleting) = signal(false);
+    let (delete_error, set_delete_error) = signal(None::<String>);
+    let is_confirmed = move || confirmation.with(|typed| typed.trim() == DELETE_CONFIRMATION);
+
+    let on_export = {
+        let api = api.clone();
+        move |_| {
+            let api = api.clone();
+            set_is_exporting.set(true);
+            set_export_error.set(None);
+            spawn_local(async move {
+                match api.export_account().await {
+                    Ok(data) => {
+                        let json = serde_json::to_string_pretty(&data).unwrap_or_default();
+                        if let Err(err) =
+                            download_text("account-export.json", "application/json", &json)
+                        {
+                            log!("Saving account export failed: {:?}", err);
+                            set_export_error
+                                .set(Some("Could not save the export file.".to_owned()));
+                        }
+                    }
+                    Err(err) => {
+                        log!("Exporting account failed: {}", err);
+                        set_export_error.set(Some(err.user_message()));
+                    }
+                }
+                set_is_exporting.set(false);
+            });
+        }
+    };
+
+    let on_delete = move |_| {
+        if !is_confirmed() {
+            return;
+        }
+        let api = api.clone();
+        let navigate = navigate.clone();
+        set_is_deleting.set(true);
+        set_delete_error.set(None);
+        spawn_local(async move {
+            match api.delete_account().await {
+                // Same as logging out: the backend session is gone.
+                Ok(()) => {
+                    end_session(set_global_state);
+                    navigate("/", Default::default());
+                }
+                Err(err) => {
+                    log!("Deleting account failed: {}", err);
+                    set_delete_error.set(Some(err.user_message()));
+                    set_is_deleting.set(false);
+                }
+            }
+        });
+    };
+
+    view! {
+        <section class="danger-zone">
+            <h3>"Danger Zone"</h3>
+            <div class="danger-item">
+                <p>"Download a copy of all data stored for your account."</p>
+                <button type="button" on:click=on_export disabled=move || is_exporting.get()>
+                    {move || if is_exporting.get() { "Preparing export…" } else { "Export my data" }}
+                </button>
+                {move || export_error.get().map(|msg| view! { <p class="form-error">{msg}</p> })}
+            </div>
+            <div class="danger-item">
+                <p>"Deleting your account is permanent and cannot be undone."</p>
+                <label for="delete_confirmation">
+                    {format!("Type \"{}\" to confirm:", DELETE_CONFIRMATION)}
+                </label>
+                <input
+                    id="delete_confirmation"
+                    type="text"
+                    autocomplete="off"
+                    on:input=move |ev| set_confirmation.set(event_target_value(&ev))
+                />
+                <button
+                    type="button"
+                    class="danger-button"
+                    on:click=on_delete
+                    disabled=move || !is_confirmed() || is_deleting.get()
+                >
+                    "Delete my account"
+                </button>
+                {move || delete_error.get().map(|msg| view! { <p class="form-error">{msg}</p> })}
+            </div>
+        </section>
+    }
+}
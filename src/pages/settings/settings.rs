@@ -0,0 +1,212 @@
+use leptos::{logging::log, prelude::*, task::spawn_local};
+
+use crate::{
+    api::client::ApiClient,
+    components::{
+        document_head::{use_head, PageMeta},
+        field_error::FieldErrorMessage,
+        location_select::{CountrySubdivisionSelect, LanguageSelect},
+    },
+    dto::user::{UpdateProfileRequest, UserProfile},
+    pages::settings::danger_zone::DangerZone,
+    pages::signup::signup::SIGNUP_STYLE,
+    utils::validation::{all_valid, apply_field_errors, user_name_rules, Field, Rule},
+};
+
+/// Outcome of the last save, shown under the form.
+#[derive(Clone, Debug, PartialEq)]
+enum SaveStatus {
+    Idle,
+    Saving,
+    Saved,
+    Failed(String),
+}
+
+#[component]
+pub fn Settings() -> impl IntoView {
+    use_head(|| PageMeta::titled("Account settings"));
+    let api = use_context::<ApiClient>().expect("api client not provided");
+
+    let user_name = Field::new(user_name_rules());
+    let user_country = Field::new(vec![Rule::SelectedOption]);
+    let user_language = Field::new(vec![Rule::SelectedOption]);
+    let user_subdivision = RwSignal::new(None::<i32>);
+
+    // The profile as the backend (optimistically) has it; `None` until loaded.
+    let saved_profile = RwSignal::new(None::<UserProfile>);
+    let (load_error, set_load_error) = signal(None::<String>);
+    let (save_status, set_save_status) = signal(SaveStatus::Idle);
+
+    let fill_form = move |profile: &UserProfile| {
+        user_name.reset(profile.user_name.clone());
+        user_country.reset(profile.user_country.to_string());
+        user_language.reset(profile.user_language.to_string());
+        user_subdivision.set(profile.user_subdivision);
+    };
+
+    let form_profile = move || UserProfile {
+        user_name: user_name.value.get(),
+        // Not editable here; see ChangeEmail.
+        user_email: saved_profile
+            .with(|saved| saved.as_ref().map(|saved| saved.user_email.clone()))
+            .unwrap_or_default(),
+        user_country: user_country.value.get().parse().unwrap_or_default(),
+        user_subdivision: user_subdivision.get(),
+        user_language: user_language.value.get().parse().unwrap_or_default(),
+    };
+
+    let is_dirty = move || {
+        saved_profile
+            .get()
+            .is_some_and(|saved| saved != form_profile())
+    };
+    let can_submit = move || {
+        is_dirty()
+            && save_status.get() != SaveStatus::Saving
+            && all_valid(&[user_name, user_country, user_language])
+    };
+
+    // Load the current profile once.
+    spawn_local({
+        let api = api.clone();
+        async move {
+            match api.profile().await {
+                Ok(profile) => {
+                    fill_form(&profile);
+                    saved_profile.set(Some(profile));
+                }
+                Err(err) => {
+                    log!("Loading profile failed: {}", err);
+                    set_load_error.set(Some(err.user_message()));
+                }
+            }
+        }
+    });
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if !can_submit() {
+            return;
+        }
+        let previous = saved_profile.get_untracked();
+        let updated = form_profile();
+
+        // Optimistically treat the edit as saved; roll back if the backend refuses.
+        saved_profile.set(Some(updated.clone()));
+        set_save_status.set(SaveStatus::Saving);
+
+        let request = UpdateProfileRequest {
+            user_name: updated.user_name,
+            user_country: updated.user_country,
+            user_subdivision: updated.user_subdivision,
+            user_language: updated.user_language,
+        };
+        let api = api.clone();
+        spawn_local(async move {
+            match api.update_profile(&request).await {
+                Ok(profile) => {
+                    saved_profile.set(Some(profile));
+                    set_save_status.set(SaveStatus::Saved);
+                }
+                Err(err) => {
+                    log!("Updating profile failed: {}", err);
+                    if let Some(previous) = &previous {
+                        fill_form(previous);
+                    }
+                    saved_profile.set(previous);
+                    // Rolled-back fields still get the backend's explanation.
+                    apply_field_errors(
+                        &[
+                            ("user_name", user_name),
+                            ("user_country", user_country),
+                            ("user_language", user_language),
+                        ],
+                        err.field_errors(),
+                    );
+                    set_save_status.set(SaveStatus::Failed(err.user_message()));
+                }
+            }
+        });
+    };
+
+    view! {
+        <>
+            <style>{SIGNUP_STYLE}</style>
+            <style>{include_str!("./settings.css")}</style>
+            <div class="container">
+                <div class="signup-form settings-form">
+                    <h2>"Account Settings"</h2>
+                    {move || {
+                        load_error.get().map(|msg| view! { <p class="form-error">{msg}</p> })
+                    }}
+                    <Show
+                        when=move || saved_profile.get().is_some()
+                        fallback=move || {
+                            view! {
+                                <Show when=move || load_error.get().is_none()>
+                                    <p>"Loading your profile…"</p>
+                                </Show>
+                            }
+                        }
+                    >
+                        <form on:submit=on_submit.clone()>
+                            <div>
+                                <label for="user_name">"Username*:"</label>
+                                <input
+                                    id="user_name"
+                                    type="text"
+                                    prop:value=move || user_name.value.get()
+                                    on:input=move |ev| user_name.set(event_target_value(&ev))
+                                    on:blur=move |_| user_name.touch()
+                                />
+                                <FieldErrorMessage field=user_name />
+                            </div>
+                            <div>
+                                <label>"Email:"</label>
+                                <p class="settings-value">
+                                    {move || {
+                                        saved_profile
+                                            .get()
+                                            .map(|profile| profile.user_email)
+                                            .unwrap_or_default()
+                                    }}
+                                    <a href="/account/settings/email">"Change"</a>
+                                </p>
+                            </div>
+                            <CountrySubdivisionSelect
+                                country=user_country
+                                subdivision=user_subdivision
+                            />
+                            <LanguageSelect language=user_language />
+                            <button type="submit" disabled=move || !can_submit()>
+                                "Save Changes"
+                            </button>
+                        </form>
+                        {move || match save_status.get() {
+                            SaveStatus::Idle => ().into_any(),
+                            SaveStatus::Saving => {
+                                view! { <p class="save-status">"Saving…"</p> }.into_any()
+                            }
+                            SaveStatus::Saved => {
+                                view! { <p class="save-status saved">"Changes saved."</p> }
+                                    .into_any()
+                            }
+                            SaveStatus::Failed(message) => {
+                                view! {
+                                    <p class="save-status failed">
+                                        {format!("Could not save: {}", message)}
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                        }}
+                        <div class="settings-links">
+                            <a href="/account/settings/password">"Change password"</a>
+                        </div>
+                        <DangerZone />
+                    </Show>
+                </div>
+            </div>
+        </>
+    }
+}
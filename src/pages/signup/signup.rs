@@ -1,336 +1,118 @@
 use leptos::{logging::log, prelude::*, task::spawn_local};
 use leptos_router::hooks::use_navigate;
 use urlencoding::encode;
-use web_sys::wasm_bindgen::JsCast;
 
-use crate::{dto::api_response::ResponseFormat, GlobalAppState};
+use crate::{
+    api::client::ApiClient,
+    components::{
+        document_head::{use_head, PageMeta},
+        field_error::FieldErrorMessage,
+        location_select::{CountrySubdivisionSelect, LanguageSelect},
+        new_password::NewPasswordFields,
+    },
+    dto::auth::SignupRequest,
+    utils::{
+        password_strength::use_password_strength,
+        validation::{
+            all_valid, apply_field_errors, email_rules, new_password_rules, user_name_rules, Field,
+            Rule,
+        },
+    },
+};
 
 /// The signup form style extracted as a constant for improved readability.
 pub const SIGNUP_STYLE: &str = include_str!("./signup.css");
 
-/// Country and subdivision types
-#[derive(Clone, Debug, serde::Deserialize)]
-struct CountryData {
-    countries: Vec<IsoCountry>,
-}
-
-#[derive(Clone, serde_derive::Deserialize, Debug)]
-pub struct IsoCountry {
-    pub country_code: i32,
-    pub country_alpha2: String,
-    pub country_alpha3: String,
-    pub country_eng_name: String,
-    pub country_currency: i32,
-    pub phone_prefix: String,
-    pub country_flag: String,
-    pub is_country: bool,
-    pub country_primary_language: i32,
-}
-
-#[derive(Clone, serde_derive::Deserialize, Debug)]
-pub struct Subdivision {
-    pub subdivision_id: i32,
-    pub country_code: i32,
-    pub subdivision_code: String,
-    pub subdivision_name: String,
-    pub subdivision_type: Option<String>,
-}
-
-/// ISO Languages: This is the reply by the backend.
-#[derive(Clone, serde_derive::Deserialize, Debug)]
-pub struct IsoLanguage {
-    pub language_code: i32,
-    pub language_alpha2: String,
-    pub language_alpha3: String,
-    pub language_eng_name: String,
-}
-
-/// The request struct that you will send off to the backend.
-#[derive(serde_derive::Deserialize, serde_derive::Serialize, Clone, Debug, Default)]
-pub struct SignupRequest {
-    pub user_name: String,
-    pub user_email: String,
-    pub user_password: String,
-    pub user_country: i32,
-    pub user_language: i32,
-    pub user_subdivision: Option<i32>,
-}
-
-/// Struct representing the response from the signup endpoint.
-#[derive(Clone, serde_derive::Deserialize, Debug)]
-pub struct SignupResponse {
-    pub user_name: String,
-    pub user_email: String,
-    pub verify_by: String,
-}
-
 #[component]
 pub fn Signup() -> impl IntoView {
-    // Signals for dropdown data.
-    let (countries, set_countries) = signal(Vec::<IsoCountry>::new());
-    let (subdivisions, set_subdivisions) = signal(Vec::<Subdivision>::new());
-    let (languages, set_languages) = signal(Vec::<IsoLanguage>::new());
-
-    // Track the currently selected country (unused, so prefixed with an underscore to silence warnings).
-    let (_selected_country, set_selected_country) = signal(String::new());
-    // The main object that builds up the signup request.
-    let (request_state, set_request_state) = signal(SignupRequest::default());
-
-    // Grab global state and its setter from context.
-    let global_state =
-        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
-
-    let navigate = use_navigate();
-
-    // Wrap backend_url and api_key in Rc so they can be cloned into multiple closures.
-    let backend_url = std::rc::Rc::new(global_state.get().backend_url.clone());
-    let api_key = std::rc::Rc::new(global_state.get().api_key.clone());
-
-    // Initially fetch the list of countries.
-    spawn_local({
-        let set_countries = set_countries.clone();
-        let backend_url = backend_url.clone();
-        let api_key = api_key.clone();
-        async move {
-            let url = format!("{}/dropdown/country", backend_url.as_ref());
-            match gloo_net::http::Request::get(&url)
-                .header("x-api-key", api_key.as_ref())
-                .send()
-                .await
-            {
-                Ok(response) => match response.json::<ResponseFormat<CountryData>>().await {
-                    Ok(countries_resp) => {
-                        if countries_resp.success {
-                            log!(
-                                "Fetched {} countries successfully. Meta: {:?}",
-                                countries_resp.data.countries.len(),
-                                countries_resp.meta
-                            );
-                            set_countries.set(countries_resp.data.countries);
-                        } else {
-                            log!("Server failure fetching countries: {:?}", countries_resp);
-                        }
-                    }
-                    Err(err) => log!("Error parsing countries JSON response: {:?}", err),
-                },
-                Err(err) => log!("Error sending request: {:?}", err),
-            }
-        }
+    use_head(|| PageMeta::titled("Sign up"));
+    // One validated field per request value; subdivision is optional.
+    let user_name = Field::new(user_name_rules());
+    let user_email = Field::new(email_rules());
+    let user_password = Field::new(new_password_rules());
+    let user_password_confirm =
+        Field::new(vec![Rule::Required, Rule::Matches(user_password.value)]);
+    // The password must not be guessable from what the user typed above it.
+    let password_strength = use_password_strength(user_password, move || {
+        let email = user_email.value.get();
+        let email_local = email.split('@').next().unwrap_or_default().to_owned();
+        vec![user_name.value.get(), email_local]
     });
-
-    // Initially fetch the list of languages.
-    spawn_local({
-        let set_languages = set_languages.clone();
-        let backend_url = backend_url.clone();
-        let api_key = api_key.clone();
-        async move {
-            let url = format!("{}/dropdown/language", backend_url.as_ref());
-            match gloo_net::http::Request::get(&url)
-                .header("x-api-key", api_key.as_ref())
-                .send()
-                .await
-            {
-                Ok(response) => match response.json::<ResponseFormat<Vec<IsoLanguage>>>().await {
-                    Ok(languages_resp) => {
-                        if languages_resp.success {
-                            log!(
-                                "Fetched {} languages successfully. Meta: {:?}",
-                                languages_resp.data.len(),
-                                languages_resp.meta
-                            );
-                            set_languages.set(languages_resp.data);
-                        } else {
-                            log!("Server failure fetching languages: {:?}", languages_resp);
-                        }
-                    }
-                    Err(err) => log!("Error parsing languages JSON response: {:?}", err),
-                },
-                Err(err) => log!("Error sending languages request: {:?}", err),
-            }
-        }
-    });
-
-    let on_subdivision_change = {
-        let set_request_state = set_request_state.clone();
-        move |ev: web_sys::Event| {
-            if let Some(target) = ev.target() {
-                let input: web_sys::HtmlSelectElement = target.unchecked_into();
-                let subdivision_val = input.value();
-                let subdivision_code = subdivision_val.parse::<i32>().ok();
-                set_request_state.update(|state| state.user_subdivision = subdivision_code);
-            }
-        }
+    let user_country = Field::new(vec![Rule::SelectedOption]);
+    let user_language = Field::new(vec![Rule::SelectedOption]);
+    let user_subdivision = RwSignal::new(None::<i32>);
+    let is_form_valid = move || {
+        all_valid(&[
+            user_name,
+            user_email,
+            user_password,
+            user_password_confirm,
+            user_country,
+            user_language,
+        ]) && password_strength.get().is_acceptable()
     };
 
-    let on_language_change = {
-        let set_request_state = set_request_state.clone();
-        move |ev: web_sys::Event| {
-            if let Some(target) = ev.target() {
-                let input: web_sys::HtmlSelectElement = target.unchecked_into();
-                let lang_val = input.value();
-                let language_code = lang_val.parse::<i32>().unwrap_or(0);
-                set_request_state.update(|state| state.user_language = language_code);
-            }
-        }
-    };
-
-    let on_name_input = {
-        let set_request_state = set_request_state.clone();
-        move |ev: web_sys::Event| {
-            if let Some(input) = ev
-                .target()
-                .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
-            {
-                let name = input.value();
-                set_request_state.update(|state| state.user_name = name);
-            }
-        }
-    };
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    // Error reported by the backend for the last submission, shown above the form.
+    let (error_message, set_error_message) = signal(None::<String>);
 
-    let on_email_input = {
-        let set_request_state = set_request_state.clone();
-        move |ev: web_sys::Event| {
-            if let Some(input) = ev
-                .target()
-                .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
-            {
-                let email = input.value();
-                set_request_state.update(|state| state.user_email = email);
-            }
-        }
-    };
+    let navigate = use_navigate();
 
-    let on_password_input = {
-        let set_request_state = set_request_state.clone();
-        move |ev: web_sys::Event| {
-            if let Some(input) = ev
-                .target()
-                .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
-            {
-                let pwd = input.value();
-                set_request_state.update(|state| state.user_password = pwd);
-            }
+    // Define the on_submit handler
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        if !is_form_valid() {
+            return;
         }
-    };
-
-    let backend_url_for_country_change = backend_url.clone();
-    let api_key_for_country_change = api_key.clone();
-    // Define the on_country_change handler
-    let on_country_change = {
-        let set_selected_country = set_selected_country.clone();
-        let set_request_state = set_request_state.clone();
-        let set_subdivisions = set_subdivisions.clone();
-        move |ev: web_sys::Event| {
-            let backend_url_for_country_change = backend_url_for_country_change.clone();
-            let api_key_for_country_change = api_key_for_country_change.clone();
-
-            if let Some(target) = ev.target() {
-                let input: web_sys::HtmlSelectElement = target.unchecked_into();
-                let country_val = input.value();
-
-                set_selected_country.set(country_val.clone());
-                let country_code = country_val.parse::<i32>().unwrap_or(0);
-                set_request_state.update(|state| {
-                    state.user_country = country_code;
-                });
-                set_request_state.update(|state| {
-                    state.user_subdivision = None;
-                });
+        set_error_message.set(None);
+        let request_state = SignupRequest {
+            user_name: user_name.value.get(),
+            user_email: user_email.value.get(),
+            user_password: user_password.value.get(),
+            // Both are guaranteed to parse by Rule::SelectedOption.
+            user_country: user_country.value.get().parse().unwrap_or_default(),
+            user_language: user_language.value.get().parse().unwrap_or_default(),
+            user_subdivision: user_subdivision.get(),
+        };
+        let api = api.clone();
+        let navigate = navigate.clone();
+        spawn_local(async move {
+            match api.signup(&request_state).await {
+                Ok(resp) => {
+                    log!("Signup Response: {:?}", resp);
+
+                    // Build the query string, encoding values in case they contain characters
+                    let query_params = format!(
+                        "?user_name={}&user_email={}&expiry_time={}",
+                        encode(&resp.user_name),
+                        encode(&resp.user_email),
+                        encode(&resp.verify_by)
+                    );
 
-                spawn_local(async move {
-                    let url = format!(
-                        "{}/dropdown/country/{}/subdivision",
-                        backend_url_for_country_change, country_val
+                    // Navigate to the signup-complete route with query parameters.
+                    navigate(
+                        &format!("/account/signup-complete{}", query_params),
+                        Default::default(),
                     );
-                    match gloo_net::http::Request::get(&url)
-                        .header("x-api-key", &api_key_for_country_change)
-                        .send()
-                        .await
-                    {
-                        Ok(response) => {
-                            match response.json::<ResponseFormat<Vec<Subdivision>>>().await {
-                                Ok(sub_div_resp) => {
-                                    if sub_div_resp.success {
-                                        log!(
-                                            "Fetched {} subdivisions successfully. Meta: {:?}",
-                                            sub_div_resp.data.len(),
-                                            sub_div_resp.meta
-                                        );
-                                        set_subdivisions.set(sub_div_resp.data);
-                                    } else {
-                                        log!(
-                                            "Server failure fetching subdivisions: {:?}",
-                                            sub_div_resp
-                                        );
-                                    }
-                                }
-                                Err(err) => {
-                                    log!("Error parsing subdivisions JSON response: {:?}", err)
-                                }
-                            }
-                        }
-                        Err(err) => log!("Error sending subdivisions request: {:?}", err),
+                }
+                Err(err) => {
+                    log!("Signup failed: {}", err);
+                    let unmatched = apply_field_errors(
+                        &[
+                            ("user_name", user_name),
+                            ("user_email", user_email),
+                            ("user_password", user_password),
+                            ("user_country", user_country),
+                            ("user_language", user_language),
+                        ],
+                        err.field_errors(),
+                    );
+                    if err.field_errors().is_empty() || !unmatched.is_empty() {
+                        set_error_message.set(Some(err.user_message()));
                     }
-                });
-            }
-        }
-    };
-
-    // Define the on_submit handler
-    let backend_url_for_on_submit = backend_url.clone();
-    let api_key_for_on_submit = api_key.clone();
-    let navigate_for_on_submit = navigate.clone();
-
-    let on_submit = {
-        let request_state = request_state.clone();
-        move |ev: leptos::ev::SubmitEvent| {
-            let backend_url_for_on_submit = backend_url_for_on_submit.clone();
-            let api_key_for_on_submit = api_key_for_on_submit.clone();
-            let navigate_for_on_submit = navigate_for_on_submit.clone();
-
-            ev.prevent_default();
-            let request_state = request_state.get();
-            spawn_local(async move {
-                let url = format!("{}/auth/signup", backend_url_for_on_submit);
-                let req = gloo_net::http::Request::post(&url)
-                    .header("x-api-key", &api_key_for_on_submit)
-                    .header("Content-Type", "application/json")
-                    .body(serde_json::to_string(&request_state).unwrap())
-                    .unwrap();
-                match req.send().await {
-                    Ok(response) => match response.json::<ResponseFormat<SignupResponse>>().await {
-                        Ok(resp) => {
-                            if resp.success {
-                                log!("Signup Response: {:?}", resp.data);
-
-                                // Extract the values to pass as query params:
-                                let user_name = &resp.data.user_name;
-                                let user_email = &resp.data.user_email;
-                                let expiry_time = &resp.data.verify_by;
-
-                                // Build the query string, encoding values in case they contain characters
-                                let query_params = format!(
-                                    "?user_name={}&user_email={}&expiry_time={}",
-                                    encode(user_name),
-                                    encode(user_email),
-                                    encode(expiry_time)
-                                );
-
-                                // Navigate to the signup-complete route with query parameters.
-                                navigate_for_on_submit(
-                                    &format!("/account/signup-complete{}", query_params),
-                                    Default::default(),
-                                );
-                            } else {
-                                log!("Signup failed at backend: {:?}", resp);
-                            }
-                        }
-                        Err(err) => log!("Error parsing signup response JSON: {:?}", err),
-                    },
-                    Err(err) => log!("Error sending signup request: {:?}", err),
                 }
-            });
-        }
+            }
+        });
     };
 
     view! {
@@ -339,6 +121,9 @@ pub fn Signup() -> impl IntoView {
             <div class="container">
                 <div class="signup-form">
                     <h2>"Sign Up"</h2>
+                    {move || {
+                        error_message.get().map(|msg| view! { <p class="form-error">{msg}</p> })
+                    }}
                     <form on:submit=on_submit>
                         <div>
                             <label for="user_name">"Username*:"</label>
@@ -346,8 +131,10 @@ pub fn Signup() -> impl IntoView {
                                 id="user_name"
                                 type="text"
                                 placeholder="Your Name"
-                                on:input=on_name_input
+                                on:input=move |ev| user_name.set(event_target_value(&ev))
+                                on:blur=move |_| user_name.touch()
                             />
+                            <FieldErrorMessage field=user_name />
                         </div>
                         <div>
                             <label for="user_email">"Email*:"</label>
@@ -355,82 +142,25 @@ pub fn Signup() -> impl IntoView {
                                 id="user_email"
                                 type="email"
                                 placeholder="Your Email"
-                                on:input=on_email_input
+                                on:input=move |ev| user_email.set(event_target_value(&ev))
+                                on:blur=move |_| user_email.touch()
                             />
+                            <FieldErrorMessage field=user_email />
                         </div>
-                        <div>
-                            <label for="user_password">"Password*:"</label>
-                            <input
-                                id="user_password"
-                                type="password"
-                                placeholder="Your Password"
-                                on:input=on_password_input
-                            />
-                        </div>
-                        <div>
-                            <label for="user_country">"Country*:"</label>
-                            <select id="user_country" on:change=on_country_change>
-                                <option value="">"Select Country"</option>
-                                {move || {
-                                    countries
-                                        .get()
-                                        .into_iter()
-                                        .map(|country| {
-                                            view! {
-                                                <option value=country
-                                                    .country_code
-                                                    .to_string()>
-                                                    {format!(
-                                                        "{} {}",
-                                                        country.country_flag,
-                                                        country.country_eng_name,
-                                                    )}
-                                                </option>
-                                            }
-                                        })
-                                        .collect_view()
-                                }}
-                            </select>
-                        </div>
-                        <div>
-                            <label for="user_subdivision">"Subdivision:"</label>
-                            <select id="user_subdivision" on:change=on_subdivision_change>
-                                <option value="">"Select Subdivision"</option>
-                                {move || {
-                                    subdivisions
-                                        .get()
-                                        .into_iter()
-                                        .map(|subdivision| {
-                                            view! {
-                                                <option value=subdivision
-                                                    .subdivision_id
-                                                    .to_string()>{subdivision.subdivision_name.clone()}</option>
-                                            }
-                                        })
-                                        .collect_view()
-                                }}
-                            </select>
-                        </div>
-                        <div>
-                            <label for="user_language">"Language*:"</label>
-                            <select id="user_language" on:change=on_language_change>
-                                <option value="">"Select Language"</option>
-                                {move || {
-                                    languages
-                                        .get()
-                                        .into_iter()
-                                        .map(|lang| {
-                                            view! {
-                                                <option value=lang
-                                                    .language_code
-                                                    .to_string()>{lang.language_eng_name.clone()}</option>
-                                            }
-                                        })
-                                        .collect_view()
-                                }}
-                            </select>
-                        </div>
-                        <button type="submit">"Sign Up"</button>
+                        <NewPasswordFields
+                            password=user_password
+                            confirm=user_password_confirm
+                            strength=password_strength
+                            id="user_password"
+                        />
+                        <CountrySubdivisionSelect
+                            country=user_country
+                            subdivision=user_subdivision
+                        />
+                        <LanguageSelect language=user_language />
+                        <button type="submit" disabled=move || !is_form_valid()>
+                            "Sign Up"
+                        </button>
                     </form>
                 </div>
             </div>
@@ -1,12 +1,97 @@
-use leptos::prelude::*;
+use chrono::{DateTime, Local, Utc};
+use leptos::{logging::log, prelude::*, task::spawn_local};
 use leptos_router::hooks::use_query_map;
 
-use crate::pages::signup::signup::SIGNUP_STYLE;
+use crate::{
+    api::client::ApiClient,
+    components::document_head::{use_head, PageMeta},
+    dto::auth::ResendVerificationRequest,
+    pages::signup::signup::SIGNUP_STYLE,
+    utils::time::{format_remaining, use_clock},
+};
+
+/// How long the user has to wait between two resend requests.
+const RESEND_COOLDOWN: chrono::Duration = chrono::Duration::seconds(60);
+
+fn parse_verify_by(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
 
 #[component]
 pub fn SignupComplete() -> impl IntoView {
+    use_head(|| PageMeta::titled("Signup complete"));
     let query = use_query_map();
     let query_map = query.get();
+    let api = use_context::<ApiClient>().expect("api client not provided");
+
+    let user_name = query_map.get("user_name").unwrap_or("Unknown".to_owned());
+    let user_email = query_map.get("user_email");
+
+    let now = use_clock();
+    // Deadline for clicking the verification link; replaced on every resend.
+    let verify_by = RwSignal::new(
+        query_map
+            .get("expiry_time")
+            .as_deref()
+            .and_then(parse_verify_by),
+    );
+    // The signup email has only just been sent, so start with a cooldown.
+    let next_resend_at = RwSignal::new(Utc::now() + RESEND_COOLDOWN);
+    let (is_sending, set_is_sending) = signal(false);
+    let (resend_message, set_resend_message) = signal(None::<String>);
+
+    let expiry_text = move || match verify_by.get() {
+        Some(deadline) => match format_remaining(deadline, now.get()) {
+            Some(remaining) => format!(
+                "Link expires in {} ({})",
+                remaining,
+                deadline.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")
+            ),
+            None => {
+                "The verification link has expired. Resend the email to get a new one.".to_owned()
+            }
+        },
+        None => "N/A".to_owned(),
+    };
+
+    let cooldown = move || format_remaining(next_resend_at.get(), now.get());
+
+    let on_resend = {
+        let user_email = user_email.clone();
+        move |_| {
+            let Some(user_email) = user_email.clone() else {
+                return;
+            };
+            let api = api.clone();
+            set_is_sending.set(true);
+            set_resend_message.set(None);
+            spawn_local(async move {
+                let request = ResendVerificationRequest { user_email };
+                match api.resend_verification_email(&request).await {
+                    Ok(resp) => {
+                        if let Some(deadline) = parse_verify_by(&resp.verify_by) {
+                            verify_by.set(Some(deadline));
+                        }
+                        set_resend_message.set(Some(format!(
+                            "A new verification email was sent to {}.",
+                            resp.user_email
+                        )));
+                    }
+                    Err(err) => {
+                        log!("Resending verification email failed: {}", err);
+                        set_resend_message.set(Some(err.user_message()));
+                    }
+                }
+                // Either way, don't let the user hammer the endpoint.
+                next_resend_at.set(Utc::now() + RESEND_COOLDOWN);
+                set_is_sending.set(false);
+            });
+        }
+    };
+
+    let has_email = user_email.is_some();
 
     view! {
         <style>{SIGNUP_STYLE}</style>
@@ -14,27 +99,25 @@ pub fn SignupComplete() -> impl IntoView {
             <div class="signup-form">
                 <h2>"Signup Complete"</h2>
                 <p>
-                    {
-                        let user_name = query_map.get("user_name").unwrap_or("Unknown".to_owned());
-                        let user_email = query_map
-                            .get("user_email")
-                            .unwrap_or("Not provided".to_owned());
-                        format!("Thank you, {} ({}).", user_name, user_email)
-                    }
-                </p>
-                <p>{"Please verify your email by"}</p>
-                <p>
-                    {
-                        let expiry_time = query_map.get("expiry_time").unwrap_or("N/A".to_owned());
-                        use chrono::{DateTime, Local};
-                        let formatted_time = DateTime::parse_from_rfc3339(&expiry_time)
-                            .map(|dt| {
-                                dt.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string()
-                            })
-                            .unwrap_or(expiry_time);
-                        format!("{}", formatted_time)
-                    }
+                    {format!(
+                        "Thank you, {} ({}).",
+                        user_name,
+                        user_email.as_deref().unwrap_or("Not provided"),
+                    )}
                 </p>
+                <p>{"Please verify your email by clicking the link we sent you."}</p>
+                <p>{expiry_text}</p>
+                <button
+                    type="button"
+                    on:click=on_resend
+                    disabled=move || !has_email || is_sending.get() || cooldown().is_some()
+                >
+                    {move || match cooldown() {
+                        Some(remaining) => format!("Resend email (available in {})", remaining),
+                        None => "Resend email".to_owned(),
+                    }}
+                </button>
+                {move || resend_message.get().map(|msg| view! { <p>{msg}</p> })}
             </div>
         </div>
     }
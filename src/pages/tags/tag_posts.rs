@@ -0,0 +1,36 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_params_map;
+
+use crate::components::{
+    document_head::{use_head, PageMeta},
+    post_list::{PostFeed, PostList},
+};
+
+/// Posts carrying one tag, at `/tags/:tag`.
+#[component]
+pub fn TagPosts() -> impl IntoView {
+    let params = use_params_map();
+    let tag = Memo::new(move |_| params.with(|params| params.get("tag").unwrap_or_default()));
+
+    use_head(move || PageMeta {
+        title: Some(format!("#{}", tag.get())),
+        description: Some(format!("Posts tagged #{}.", tag.get())),
+        ..Default::default()
+    });
+
+    view! {
+        <style>{include_str!("./tags.css")}</style>
+        <main class="post-list">
+            <header class="tag-header">
+                <h1>{move || format!("#{}", tag.get())}</h1>
+                <a href="/tags">"All tags"</a>
+            </header>
+            <PostList
+                feed=Signal::derive(move || PostFeed::Tagged(tag.get()))
+                href=Callback::new(move |page: u32| {
+                    format!("/tags/{}?page={}", urlencoding::encode(&tag.get_untracked()), page)
+                })
+            />
+        </main>
+    }
+}
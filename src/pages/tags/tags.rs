@@ -0,0 +1,79 @@
+use leptos::{logging::log, prelude::*, task::spawn_local};
+
+use crate::{
+    api::client::ApiClient,
+    components::document_head::{use_head, PageMeta},
+    dto::post::TagCount,
+};
+
+/// Font sizes, in `em`, of the rarest and the most used tag.
+const MIN_FONT_SIZE: f64 = 0.9;
+const MAX_FONT_SIZE: f64 = 2.2;
+
+/// Scales a tag's font size with its post count. Logarithmic, so one very
+/// popular tag does not shrink all the others to the minimum.
+fn font_size(post_count: u32, max_count: u32) -> f64 {
+    if max_count <= 1 {
+        return MIN_FONT_SIZE;
+    }
+    let weight = f64::from(post_count.max(1)).ln() / f64::from(max_count).ln();
+    MIN_FONT_SIZE + (MAX_FONT_SIZE - MIN_FONT_SIZE) * weight
+}
+
+/// Every tag in use, sized by how many posts carry it.
+#[component]
+pub fn Tags() -> impl IntoView {
+    use_head(|| PageMeta::titled("Tags"));
+    let api = use_context::<ApiClient>().expect("api client not provided");
+
+    // `None` while loading.
+    let (tags, set_tags) = signal(None::<Result<Vec<TagCount>, String>>);
+    spawn_local(async move {
+        let result = api.tags().await.map_err(|err| {
+            log!("Fetching tags failed: {}", err);
+            err.user_message()
+        });
+        set_tags.set(Some(result));
+    });
+
+    view! {
+        <style>{include_str!("./tags.css")}</style>
+        <main class="tag-page">
+            <h1>"Tags"</h1>
+            {move || match tags.get() {
+                None => view! { <p class="tag-message">"Loading tags…"</p> }.into_any(),
+                Some(Err(message)) => view! { <p class="tag-message">{message}</p> }.into_any(),
+                Some(Ok(tags)) if tags.is_empty() => {
+                    view! { <p class="tag-message">"No tags yet."</p> }.into_any()
+                }
+                Some(Ok(mut tags)) => {
+                    tags.sort_by_key(|tag| tag.tag.to_lowercase());
+                    let max_count = tags.iter().map(|tag| tag.post_count).max().unwrap_or(1);
+                    view! {
+                        <ul class="tag-cloud">
+                            {tags
+                                .into_iter()
+                                .map(|tag| {
+                                    let style = format!(
+                                        "font-size: {:.2}em;",
+                                        font_size(tag.post_count, max_count),
+                                    );
+                                    let href = format!("/tags/{}", urlencoding::encode(&tag.tag));
+                                    view! {
+                                        <li>
+                                            <a href=href style=style>
+                                                {format!("#{}", tag.tag)}
+                                            </a>
+                                            <span class="tag-count">{tag.post_count}</span>
+                                        </li>
+                                    }
+                                })
+                                .collect_view()}
+                        </ul>
+                    }
+                        .into_any()
+                }
+            }}
+        </main>
+    }
+}
@@ -1,4 +1,183 @@
-use leptos::prelude::*;
+use leptos::{logging::log, prelude::*, task::spawn_local};
+use leptos_router::hooks::use_query_map;
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::{
+    api::{client::ApiClient, error::error_codes, session::revive_session},
+    components::document_head::{use_head, PageMeta},
+    dto::auth::{ResendVerificationRequest, VerifyEmailRequest},
+    pages::signup::signup::SIGNUP_STYLE,
+    GlobalAppState,
+};
+
+/// Where the verification attempt currently stands.
+#[derive(Clone, Debug, PartialEq)]
+enum VerificationStatus {
+    Verifying,
+    Verified(String),
+    AlreadyVerified,
+    Expired,
+    Failed(String),
+}
 
 #[component]
-pub fn ValidateEmail() -> impl IntoView {}
+pub fn ValidateEmail() -> impl IntoView {
+    use_head(|| PageMeta::titled("Email verification"));
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let global_state =
+        use_context::<ReadSignal<GlobalAppState>>().expect("global_state not provided");
+    let set_global_state =
+        use_context::<WriteSignal<GlobalAppState>>().expect("global_state setter not provided");
+    let query = use_query_map();
+
+    let (status, set_status) = signal(VerificationStatus::Verifying);
+
+    // Submit the token from the email link as soon as the page loads.
+    match query.get_untracked().get("token") {
+        Some(token) => spawn_local({
+            let api = api.clone();
+            async move {
+                let request = VerifyEmailRequest {
+                    email_validation_token: token,
+                };
+                let next_status = match api.verify_email(&request).await {
+                    Ok(resp) => {
+                        // Verifying a changed email updates the logged-in identity.
+                        if global_state.get_untracked().is_logged_in {
+                            revive_session(&api, set_global_state).await;
+                        }
+                        VerificationStatus::Verified(resp.user_email)
+                    }
+                    Err(err) => {
+                        log!("Email verification failed: {}", err);
+                        match err.error_code() {
+                            Some(error_codes::TOKEN_EXPIRED) => VerificationStatus::Expired,
+                            Some(error_codes::EMAIL_ALREADY_VERIFIED) => {
+                                VerificationStatus::AlreadyVerified
+                            }
+                            _ => VerificationStatus::Failed(err.user_message()),
+                        }
+                    }
+                };
+                set_status.set(next_status);
+            }
+        }),
+        None => set_status.set(VerificationStatus::Failed(
+            "This verification link is missing its token.".to_owned(),
+        )),
+    }
+
+    view! {
+        <>
+            <style>{SIGNUP_STYLE}</style>
+            <style>{include_str!("./validate_email.css")}</style>
+            <div class="container">
+                <div class="signup-form validate-email">
+                    <h2>"Email Verification"</h2>
+                    {move || match status.get() {
+                        VerificationStatus::Verifying => {
+                            view! { <p>"Verifying your email…"</p> }.into_any()
+                        }
+                        VerificationStatus::Verified(email) => {
+                            view! {
+                                <p class="status-success">
+                                    {format!("{} has been verified.", email)}
+                                </p>
+                                <a class="action-link" href="/account/login">
+                                    "Continue to Log In"
+                                </a>
+                            }
+                                .into_any()
+                        }
+                        VerificationStatus::AlreadyVerified => {
+                            view! {
+                                <p class="status-success">"This email is already verified."</p>
+                                <a class="action-link" href="/account/login">
+                                    "Continue to Log In"
+                                </a>
+                            }
+                                .into_any()
+                        }
+                        VerificationStatus::Expired => {
+                            view! {
+                                <p class="status-error">"This verification link has expired."</p>
+                                <ResendVerification />
+                            }
+                                .into_any()
+                        }
+                        VerificationStatus::Failed(message) => {
+                            view! {
+                                <p class="status-error">{message}</p>
+                                <ResendVerification />
+                            }
+                                .into_any()
+                        }
+                    }}
+                </div>
+            </div>
+        </>
+    }
+}
+
+/// Lets the user request a fresh verification email for their address.
+#[component]
+fn ResendVerification() -> impl IntoView {
+    let api = use_context::<ApiClient>().expect("api client not provided");
+
+    let (email, set_email) = signal(String::new());
+    let (message, set_message) = signal(None::<String>);
+    let (is_sending, set_is_sending) = signal(false);
+
+    let on_email_input = move |ev: web_sys::Event| {
+        if let Some(input) = ev
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+        {
+            set_email.set(input.value());
+        }
+    };
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let request = ResendVerificationRequest {
+            user_email: email.get(),
+        };
+        let api = api.clone();
+        set_is_sending.set(true);
+        set_message.set(None);
+        spawn_local(async move {
+            match api.resend_verification_email(&request).await {
+                Ok(resp) => set_message.set(Some(format!(
+                    "A new verification email was sent to {}.",
+                    resp.user_email
+                ))),
+                Err(err) => {
+                    log!("Resending verification email failed: {}", err);
+                    set_message.set(Some(err.user_message()));
+                }
+            }
+            set_is_sending.set(false);
+        });
+    };
+
+    view! {
+        <form on:submit=on_submit>
+            <div>
+                <label for="resend_email">"Email*:"</label>
+                <input
+                    id="resend_email"
+                    type="email"
+                    placeholder="Your Email"
+                    on:input=on_email_input
+                />
+            </div>
+            <button type="submit" disabled=move || is_sending.get()>
+                "Resend verification email"
+            </button>
+        </form>
+        {move || message.get().map(|msg| view! { <p class="resend-message">{msg}</p> })}
+        <a class="action-link" href="/account/login">
+            "Back to Log In"
+        </a>
+    }
+}
@@ -1,11 +1,445 @@
-use leptos::prelude::*;
+use std::{cmp::Reverse, collections::BTreeMap};
 
+use leptos::{
+    ev::{self, KeyboardEvent},
+    logging::log,
+    prelude::*,
+    task::spawn_local,
+};
+use leptos_router::hooks::use_query_map;
+
+use crate::{
+    api::client::ApiClient,
+    components::document_head::{use_head, PageMeta},
+    dto::project::{Project, Screenshot},
+    utils::{link::is_safe_link, time::format_period},
+};
+
+/// How the grid is ordered, from the `sort` query parameter.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum SortOrder {
+    /// Most recently started first.
+    #[default]
+    Newest,
+    Name,
+}
+
+impl SortOrder {
+    fn from_param(param: Option<&str>) -> Self {
+        match param {
+            Some("name") => Self::Name,
+            _ => Self::Newest,
+        }
+    }
+
+    /// `None` for the default, which stays out of the URL.
+    fn param(self) -> Option<&'static str> {
+        match self {
+            Self::Newest => None,
+            Self::Name => Some("name"),
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Newest => "Newest",
+            Self::Name => "Name",
+        }
+    }
+
+    fn sort(self, projects: &mut [Project]) {
+        match self {
+            Self::Newest => projects.sort_by_key(|project| Reverse(project.started_on)),
+            Self::Name => projects.sort_by_key(|project| project.title.to_lowercase()),
+        }
+    }
+}
+
+/// The grid's view as stored in the URL, so filtered views can be shared:
+/// `/works?tech=rust,wasm&sort=name`.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct WorksView {
+    /// Lowercased; a project has to use all of them to be shown.
+    techs: Vec<String>,
+    sort: SortOrder,
+}
+
+impl WorksView {
+    fn href(&self) -> String {
+        let mut params = Vec::new();
+        if !self.techs.is_empty() {
+            params.push(format!(
+                "tech={}",
+                urlencoding::encode(&self.techs.join(","))
+            ));
+        }
+        if let Some(sort) = self.sort.param() {
+            params.push(format!("sort={}", sort));
+        }
+        if params.is_empty() {
+            "/works".to_owned()
+        } else {
+            format!("/works?{}", params.join("&"))
+        }
+    }
+
+    fn with_tech_toggled(&self, tech: &str) -> Self {
+        let tech = tech.to_lowercase();
+        let mut techs = self.techs.clone();
+        match techs.iter().position(|selected| *selected == tech) {
+            Some(index) => {
+                techs.remove(index);
+            }
+            None => techs.push(tech),
+        }
+        Self {
+            techs,
+            ..self.clone()
+        }
+    }
+
+    fn matches(&self, project: &Project) -> bool {
+        self.techs.iter().all(|selected| {
+            project
+                .tech_stack
+                .iter()
+                .any(|tech| tech.to_lowercase() == *selected)
+        })
+    }
+}
+
+/// Every tech used across `projects`, keyed by its lowercase form, with the
+/// first spelling seen and how many projects use it.
+fn tech_counts(projects: &[Project]) -> BTreeMap<String, (String, usize)> {
+    let mut counts = BTreeMap::<String, (String, usize)>::new();
+    for tech in projects.iter().flat_map(|project| &project.tech_stack) {
+        counts
+            .entry(tech.to_lowercase())
+            .or_insert_with(|| (tech.clone(), 0))
+            .1 += 1;
+    }
+    counts
+}
+
+/// Drops the links and screenshots the page must not render; projects come
+/// from the backend.
+fn without_unsafe_links(mut project: Project) -> Project {
+    project.repo_url = project.repo_url.filter(|url| is_safe_link(url));
+    project.demo_url = project.demo_url.filter(|url| is_safe_link(url));
+    project.screenshots.retain(|shot| is_safe_link(&shot.url));
+    project
+}
+
+/// The portfolio: a filterable, sortable grid of projects.
 #[component]
 pub fn Works() -> impl IntoView {
+    use_head(|| PageMeta::titled("Works"));
+    let api = use_context::<ApiClient>().expect("api client not provided");
+    let query = use_query_map();
+    let works_view = Memo::new(move |_| {
+        query.with(|query| WorksView {
+            techs: query
+                .get_str("tech")
+                .unwrap_or_default()
+                .split(',')
+                .map(|tech| tech.trim().to_lowercase())
+                .filter(|tech| !tech.is_empty())
+                .collect(),
+            sort: SortOrder::from_param(query.get_str("sort")),
+        })
+    });
+
+    // `None` while loading.
+    let (projects, set_projects) = signal(None::<Result<Vec<Project>, String>>);
+    spawn_local(async move {
+        let result = api
+            .projects()
+            .await
+            .map(|projects| projects.into_iter().map(without_unsafe_links).collect())
+            .map_err(|err| {
+                log!("Fetching projects failed: {}", err);
+                err.user_message()
+            });
+        set_projects.set(Some(result));
+    });
+
+    let selected = RwSignal::new(None::<Project>);
+
+    let filters = move || {
+        let Some(Ok(projects)) = projects.get() else {
+            return ().into_any();
+        };
+        let current = works_view.get();
+        let chips = tech_counts(&projects)
+            .into_iter()
+            .map(|(key, (tech, count))| {
+                let is_active = current.techs.contains(&key);
+                let href = current.with_tech_toggled(&key).href();
+                view! {
+                    <li>
+                        <a class="tech-chip" class:active=is_active href=href>
+                            {tech}
+                            <span class="tech-count">{count}</span>
+                        </a>
+                    </li>
+                }
+            })
+            .collect_view();
+        let sorts = [SortOrder::Newest, SortOrder::Name]
+            .into_iter()
+            .map(|sort| {
+                let href = WorksView {
+                    sort,
+                    ..current.clone()
+                }
+                .href();
+                view! {
+                    <a class="works-sort" class:active=current.sort == sort href=href>
+                        {sort.label()}
+                    </a>
+                }
+            })
+            .collect_view();
+        let clear = (!current.techs.is_empty()).then(|| {
+            let href = WorksView {
+                techs: Vec::new(),
+                ..current.clone()
+            }
+            .href();
+            view! {
+                <a class="works-clear" href=href>
+                    "Clear filters"
+                </a>
+            }
+        });
+
+        view! {
+            <div class="works-controls">
+                <ul class="tech-chips">{chips}</ul>
+                <div class="works-sorts">"Sort by " {sorts} {clear}</div>
+            </div>
+        }
+        .into_any()
+    };
+
+    let grid = move || match projects.get() {
+        None => view! { <p class="works-message">"Loading projects…"</p> }.into_any(),
+        Some(Err(message)) => view! { <p class="works-message">{message}</p> }.into_any(),
+        Some(Ok(projects)) => {
+            let current = works_view.get();
+            let mut shown: Vec<Project> = projects
+                .into_iter()
+                .filter(|project| current.matches(project))
+                .collect();
+            if shown.is_empty() {
+                return view! { <p class="works-message">"No projects match these filters."</p> }
+                    .into_any();
+            }
+            current.sort.sort(&mut shown);
+            view! {
+                <ul class="works-grid">
+                    {shown
+                        .into_iter()
+                        .map(|project| view! { <ProjectCard project=project selected=selected /> })
+                        .collect_view()}
+                </ul>
+            }
+            .into_any()
+        }
+    };
+
     view! {
-        <main>
+        <style>{include_str!("./works.css")}</style>
+        <main class="works-page">
             <h1>"Works"</h1>
-            <p>"Check out our projects and portfolio here."</p>
+            {filters}
+            {grid}
+            {move || {
+                selected
+                    .get()
+                    .map(|project| {
+                        view! {
+                            <ProjectModal project=project on_close=Callback::new(move |()| selected.set(None)) />
+                        }
+                    })
+            }}
         </main>
     }
 }
+
+/// A grid tile; opens the detail modal when clicked.
+#[component]
+fn ProjectCard(project: Project, selected: RwSignal<Option<Project>>) -> impl IntoView {
+    let thumbnail = project.screenshots.first().cloned();
+    let period = format_period(project.started_on, project.finished_on);
+    let title = project.title.clone();
+    let description = project.description.clone();
+    let tech_stack = project.tech_stack.clone();
+    let project = StoredValue::new(project);
+
+    view! {
+        <li>
+            <button
+                type="button"
+                class="project-card"
+                on:click=move |_| selected.set(Some(project.get_value()))
+            >
+                {thumbnail
+                    .map(|shot| {
+                        view! {
+                            <img
+                                class="project-thumbnail"
+                                src=shot.url
+                                alt=shot.caption.unwrap_or_default()
+                                loading="lazy"
+                            />
+                        }
+                    })}
+                <span class="project-title">{title}</span>
+                <span class="project-period">{period}</span>
+                <span class="project-description">{description}</span>
+                <TechList tech_stack=tech_stack />
+            </button>
+        </li>
+    }
+}
+
+#[component]
+fn TechList(tech_stack: Vec<String>) -> impl IntoView {
+    view! {
+        <span class="project-tech">
+            {tech_stack
+                .into_iter()
+                .map(|tech| view! { <span class="tech-chip">{tech}</span> })
+                .collect_view()}
+        </span>
+    }
+}
+
+/// A project's full details over the page. Escape or clicking the backdrop
+/// closes it; the arrow keys page through the screenshots.
+#[component]
+fn ProjectModal(project: Project, on_close: Callback<()>) -> impl IntoView {
+    let screenshot_count = project.screenshots.len();
+    let current = RwSignal::new(0_usize);
+    let step = Callback::new(move |delta: isize| {
+        if screenshot_count > 1 {
+            current.update(|index| {
+                *index = (*index as isize + delta).rem_euclid(screenshot_count as isize) as usize
+            });
+        }
+    });
+
+    let keys = window_event_listener(ev::keydown, move |ev: KeyboardEvent| {
+        match ev.key().as_str() {
+            "Escape" => on_close.run(()),
+            "ArrowLeft" => step.run(-1),
+            "ArrowRight" => step.run(1),
+            _ => {}
+        }
+    });
+    on_cleanup(move || keys.remove());
+
+    let period = format_period(project.started_on, project.finished_on);
+    let links = [
+        ("Source", project.repo_url.clone()),
+        ("Live demo", project.demo_url.clone()),
+    ]
+    .into_iter()
+    .filter_map(|(label, url)| {
+        url.map(|url| {
+            view! {
+                <a href=url target="_blank" rel="noopener noreferrer">
+                    {label}
+                </a>
+            }
+        })
+    })
+    .collect_view();
+
+    view! {
+        <div class="project-backdrop" on:click=move |_| on_close.run(())>
+            <div
+                class="project-modal"
+                role="dialog"
+                aria-modal="true"
+                aria-label=project.title.clone()
+                on:click=|ev| ev.stop_propagation()
+            >
+                <button type="button" class="project-close" aria-label="Close" on:click=move |_| on_close.run(())>
+                    "×"
+                </button>
+                {(screenshot_count > 0)
+                    .then(|| {
+                        view! {
+                            <Carousel screenshots=project.screenshots.clone() current=current step=step />
+                        }
+                    })}
+                <h2>{project.title.clone()}</h2>
+                <p class="project-period">{period}</p>
+                <p class="project-description">{project.description.clone()}</p>
+                <TechList tech_stack=project.tech_stack.clone() />
+                <p class="project-links">{links}</p>
+            </div>
+        </div>
+    }
+}
+
+/// One screenshot at a time, with previous/next buttons and a dot per image.
+#[component]
+fn Carousel(
+    screenshots: Vec<Screenshot>,
+    current: RwSignal<usize>,
+    step: Callback<isize>,
+) -> impl IntoView {
+    let count = screenshots.len();
+    let screenshots = StoredValue::new(screenshots);
+    let shown = move || screenshots.with_value(|shots| shots[current.get().min(count - 1)].clone());
+
+    view! {
+        <figure class="carousel">
+            <div class="carousel-frame">
+                {move || {
+                    let shot = shown();
+                    view! { <img src=shot.url alt=shot.caption.unwrap_or_default() /> }
+                }}
+                <Show when=move || { count > 1 }>
+                    <button
+                        type="button"
+                        class="carousel-prev"
+                        aria-label="Previous screenshot"
+                        on:click=move |_| step.run(-1)
+                    >
+                        "‹"
+                    </button>
+                    <button
+                        type="button"
+                        class="carousel-next"
+                        aria-label="Next screenshot"
+                        on:click=move |_| step.run(1)
+                    >
+                        "›"
+                    </button>
+                </Show>
+            </div>
+            {move || shown().caption.map(|caption| view! { <figcaption>{caption}</figcaption> })}
+            <Show when=move || { count > 1 }>
+                <div class="carousel-dots">
+                    {(0..count)
+                        .map(|index| {
+                            view! {
+                                <button
+                                    type="button"
+                                    class="carousel-dot"
+                                    class:active=move || current.get() == index
+                                    aria-label=format!("Screenshot {}", index + 1)
+                                    on:click=move |_| current.set(index)
+                                ></button>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+            </Show>
+        </figure>
+    }
+}
@@ -0,0 +1,15 @@
+use leptos::prelude::window;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{js_sys::Reflect, wasm_bindgen::JsValue};
+
+/// Copies `text` to the clipboard. `navigator.clipboard` only exists in
+/// secure contexts, so on a plain-HTTP host this fails instead of throwing.
+pub async fn copy_text(text: &str) -> Result<(), JsValue> {
+    let navigator = window().navigator();
+    if !Reflect::has(&navigator, &JsValue::from_str("clipboard"))? {
+        return Err(JsValue::from_str("the clipboard is not available here"));
+    }
+    JsFuture::from(navigator.clipboard().write_text(text))
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use leptos::prelude::set_timeout;
+use web_sys::{
+    js_sys::Array,
+    wasm_bindgen::{JsCast, JsValue},
+    Blob, BlobPropertyBag, HtmlAnchorElement, Url,
+};
+
+/// How long the Blob URL outlives the click. Firefox and Safari start the
+/// download asynchronously and cancel it if the URL is revoked first.
+const REVOKE_DELAY: Duration = Duration::from_secs(10);
+
+/// Hands `contents` to the browser as a file download named `filename`,
+/// via a temporary Blob URL and a synthetic link click.
+pub fn download_text(filename: &str, mime_type: &str, contents: &str) -> Result<(), JsValue> {
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let parts = Array::of1(&JsValue::from_str(contents));
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let document = leptos::prelude::document();
+    let link: HtmlAnchorElement = document.create_element("a")?.unchecked_into();
+    link.set_href(&url);
+    link.set_download(filename);
+    link.style().set_property("display", "none")?;
+    // Some browsers ignore clicks on links outside the document.
+    let body = document.body().ok_or("document has no body")?;
+    body.append_child(&link)?;
+    link.click();
+
+    set_timeout(
+        move || {
+            link.remove();
+            let _ = Url::revoke_object_url(&url);
+        },
+        REVOKE_DELAY,
+    );
+    Ok(())
+}
@@ -0,0 +1,422 @@
+use chrono::{DateTime, Utc};
+use web_sys::{Document, DomParser, Element, SupportedType};
+
+use crate::utils::{head::upsert_head_element, link::is_safe_link};
+
+/// The feed flavours the backend publishes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl FeedFormat {
+    pub const ALL: [FeedFormat; 3] = [FeedFormat::Rss, FeedFormat::Atom, FeedFormat::Json];
+
+    pub fn from_param(param: &str) -> Option<Self> {
+        match param {
+            "rss" => Some(FeedFormat::Rss),
+            "atom" => Some(FeedFormat::Atom),
+            "json" => Some(FeedFormat::Json),
+            _ => None,
+        }
+    }
+
+    pub fn param(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "rss",
+            FeedFormat::Atom => "atom",
+            FeedFormat::Json => "json",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "RSS",
+            FeedFormat::Atom => "Atom",
+            FeedFormat::Json => "JSON Feed",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml",
+            FeedFormat::Atom => "application/atom+xml",
+            FeedFormat::Json => "application/feed+json",
+        }
+    }
+
+    /// Path on the backend.
+    pub fn path(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "/feeds/rss.xml",
+            FeedFormat::Atom => "/feeds/atom.xml",
+            FeedFormat::Json => "/feeds/feed.json",
+        }
+    }
+}
+
+/// The absolute URL of a feed, as feed readers need it. A same-origin
+/// backend (empty `backend_url`) resolves against the page's origin.
+pub fn feed_url(backend_url: &str, format: FeedFormat) -> String {
+    let base = if backend_url.is_empty() {
+        leptos::prelude::window()
+            .location()
+            .origin()
+            .unwrap_or_default()
+    } else {
+        backend_url.to_owned()
+    };
+    format!("{}{}", base, format.path())
+}
+
+/// Adds a `<link rel="alternate">` per feed to the document head, so
+/// browsers and feed readers can discover them from any page.
+pub fn install_feed_links(backend_url: &str, site_title: &str) {
+    for format in FeedFormat::ALL {
+        let title = format!("{} ({})", site_title, format.label());
+        upsert_head_element(
+            "link",
+            &format!("feed-{}", format.param()),
+            &[
+                ("rel", "alternate"),
+                ("type", format.mime_type()),
+                ("title", &title),
+                ("href", &feed_url(backend_url, format)),
+            ],
+        );
+    }
+}
+
+/// A feed boiled down to what the preview shows, whatever its format.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Feed {
+    pub title: String,
+    pub link: Option<String>,
+    pub description: Option<String>,
+    pub items: Vec<FeedItem>,
+    /// Things a feed reader would likely stumble over.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FeedItem {
+    pub title: String,
+    pub link: Option<String>,
+    pub published: Option<DateTime<Utc>>,
+    /// Plain text, with any markup stripped.
+    pub summary: Option<String>,
+}
+
+/// Parses `source` as a feed of the given format. Fails only when the
+/// document is unreadable; recoverable problems end up in `Feed::warnings`.
+pub fn parse_feed(format: FeedFormat, source: &str) -> Result<Feed, String> {
+    let mut feed = match format {
+        FeedFormat::Rss => parse_rss(&parse_xml(source)?)?,
+        FeedFormat::Atom => parse_atom(&parse_xml(source)?)?,
+        FeedFormat::Json => parse_json_feed(source)?,
+    };
+
+    let mut unsafe_links = Vec::new();
+    for link in
+        std::iter::once(&mut feed.link).chain(feed.items.iter_mut().map(|item| &mut item.link))
+    {
+        if let Some(url) = link.take_if(|url| !is_safe_link(url)) {
+            unsafe_links.push(url);
+        }
+    }
+    for url in unsafe_links {
+        feed.warnings.push(format!("“{}” is not a safe link.", url));
+    }
+
+    if feed.title.is_empty() {
+        feed.warnings.push("The feed has no title.".to_owned());
+    }
+    if feed.link.is_none() {
+        feed.warnings
+            .push("The feed does not link to the site.".to_owned());
+    }
+    for (index, item) in feed.items.iter().enumerate() {
+        let name = if item.title.is_empty() {
+            format!("Item {}", index + 1)
+        } else {
+            format!("“{}”", item.title)
+        };
+        if item.title.is_empty() {
+            feed.warnings.push(format!("{} has no title.", name));
+        }
+        if item.link.is_none() {
+            feed.warnings.push(format!("{} has no link.", name));
+        }
+        if item.published.is_none() {
+            feed.warnings
+                .push(format!("{} has no readable publication date.", name));
+        }
+    }
+    Ok(feed)
+}
+
+fn parse_xml(source: &str) -> Result<Document, String> {
+    let document = DomParser::new()
+        .and_then(|parser| parser.parse_from_string(source, SupportedType::ApplicationXml))
+        .map_err(|err| format!("Could not parse the feed: {:?}", err))?;
+    // Browsers report malformed XML as a document holding a <parsererror>.
+    if let Some(error) = document.get_elements_by_tag_name("parsererror").item(0) {
+        return Err(format!(
+            "The feed is not well-formed XML: {}",
+            error.text_content().unwrap_or_default().trim()
+        ));
+    }
+    Ok(document)
+}
+
+/// The direct children of `parent` named `name`, ignoring namespaces.
+fn children<'a>(parent: &Element, name: &'a str) -> impl Iterator<Item = Element> + 'a {
+    std::iter::successors(parent.first_element_child(), Element::next_element_sibling)
+        .filter(move |child| child.local_name() == name)
+}
+
+fn child_text(parent: &Element, name: &str) -> Option<String> {
+    children(parent, name)
+        .next()
+        .and_then(|child| child.text_content())
+        .map(|text| text.trim().to_owned())
+        .filter(|text| !text.is_empty())
+}
+
+/// Feeds usually carry escaped HTML in their summaries; the preview wants
+/// the text only.
+fn plain_text(html: &str) -> Option<String> {
+    let text = DomParser::new()
+        .and_then(|parser| parser.parse_from_string(html, SupportedType::TextHtml))
+        .ok()
+        .and_then(|document| document.body())
+        .and_then(|body| body.text_content())
+        .unwrap_or_else(|| html.to_owned());
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+fn parse_rss(document: &Document) -> Result<Feed, String> {
+    let channel = document
+        .document_element()
+        .filter(|root| root.local_name() == "rss")
+        .and_then(|rss| children(&rss, "channel").next())
+        .ok_or("This is not an RSS feed: there is no <rss><channel>.")?;
+
+    let mut warnings = Vec::new();
+    let items = children(&channel, "item")
+        .map(|item| {
+            let published = child_text(&item, "pubDate").and_then(|date| {
+                let parsed = DateTime::parse_from_rfc2822(&date).ok();
+                if parsed.is_none() {
+                    warnings.push(format!("“{}” is not an RFC 2822 date.", date));
+                }
+                parsed
+            });
+            FeedItem {
+                title: child_text(&item, "title").unwrap_or_default(),
+                link: child_text(&item, "link"),
+                published: published.map(|date| date.with_timezone(&Utc)),
+                summary: child_text(&item, "description").and_then(|html| plain_text(&html)),
+            }
+        })
+        .collect();
+
+    Ok(Feed {
+        title: child_text(&channel, "title").unwrap_or_default(),
+        link: child_text(&channel, "link"),
+        description: child_text(&channel, "description"),
+        items,
+        warnings,
+    })
+}
+
+/// Atom's `<link rel="alternate" href>`; `rel` defaults to alternate.
+fn atom_link(parent: &Element) -> Option<String> {
+    children(parent, "link")
+        .find(|link| {
+            link.get_attribute("rel")
+                .is_none_or(|rel| rel == "alternate")
+        })
+        .and_then(|link| link.get_attribute("href"))
+}
+
+fn parse_atom(document: &Document) -> Result<Feed, String> {
+    let root = document
+        .document_element()
+        .filter(|root| root.local_name() == "feed")
+        .ok_or("This is not an Atom feed: the root element is not <feed>.")?;
+
+    let mut warnings = Vec::new();
+    let items = children(&root, "entry")
+        .map(|entry| {
+            let published = child_text(&entry, "published")
+                .or_else(|| child_text(&entry, "updated"))
+                .and_then(|date| {
+                    let parsed = DateTime::parse_from_rfc3339(&date).ok();
+                    if parsed.is_none() {
+                        warnings.push(format!("“{}” is not an RFC 3339 date.", date));
+                    }
+                    parsed
+                });
+            FeedItem {
+                title: child_text(&entry, "title").unwrap_or_default(),
+                link: atom_link(&entry),
+                published: published.map(|date| date.with_timezone(&Utc)),
+                summary: child_text(&entry, "summary")
+                    .or_else(|| child_text(&entry, "content"))
+                    .and_then(|html| plain_text(&html)),
+            }
+        })
+        .collect();
+
+    Ok(Feed {
+        title: child_text(&root, "title").unwrap_or_default(),
+        link: atom_link(&root),
+        description: child_text(&root, "subtitle"),
+        items,
+        warnings,
+    })
+}
+
+/// The subset of JSON Feed 1.1 the preview reads.
+#[derive(serde_derive::Deserialize)]
+struct JsonFeed {
+    version: String,
+    #[serde(default)]
+    title: String,
+    home_page_url: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    items: Vec<JsonFeedItem>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct JsonFeedItem {
+    #[serde(default)]
+    title: String,
+    url: Option<String>,
+    date_published: Option<String>,
+    summary: Option<String>,
+    content_text: Option<String>,
+    content_html: Option<String>,
+}
+
+fn parse_json_feed(source: &str) -> Result<Feed, String> {
+    let json: JsonFeed =
+        serde_json::from_str(source).map_err(|err| format!("This is not a JSON Feed: {}", err))?;
+
+    let mut warnings = Vec::new();
+    if !json.version.starts_with("https://jsonfeed.org/version/") {
+        warnings.push(format!("Unexpected JSON Feed version “{}”.", json.version));
+    }
+    let items = json
+        .items
+        .into_iter()
+        .map(|item| {
+            let published = item.date_published.and_then(|date| {
+                let parsed = DateTime::parse_from_rfc3339(&date).ok();
+                if parsed.is_none() {
+                    warnings.push(format!("“{}” is not an RFC 3339 date.", date));
+                }
+                parsed
+            });
+            FeedItem {
+                title: item.title,
+                link: item.url,
+                published: published.map(|date| date.with_timezone(&Utc)),
+                summary: item
+                    .summary
+                    .or(item.content_text)
+                    .or_else(|| item.content_html.and_then(|html| plain_text(&html))),
+            }
+        })
+        .collect();
+
+    Ok(Feed {
+        title: json.title,
+        link: json.home_page_url,
+        description: json.description,
+        items,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json_feed(items: &str) -> String {
+        format!(
+            r#"{{
+                "version": "https://jsonfeed.org/version/1.1",
+                "title": "Blog",
+                "home_page_url": "https://blog.example.com/",
+                "items": [{}]
+            }}"#,
+            items
+        )
+    }
+
+    #[test]
+    fn json_feed_items_are_read() {
+        let source = json_feed(
+            r#"{
+                "id": "1",
+                "title": "Hello",
+                "url": "https://blog.example.com/posts/hello",
+                "date_published": "2025-03-01T09:30:00+09:00",
+                "content_text": "First post"
+            }"#,
+        );
+        let feed = parse_feed(FeedFormat::Json, &source).unwrap();
+        assert_eq!(feed.title, "Blog");
+        assert_eq!(feed.link.as_deref(), Some("https://blog.example.com/"));
+        assert_eq!(feed.items.len(), 1);
+        let item = &feed.items[0];
+        assert_eq!(item.title, "Hello");
+        assert_eq!(item.summary.as_deref(), Some("First post"));
+        assert_eq!(
+            item.published.map(|date| date.to_rfc3339()).as_deref(),
+            Some("2025-03-01T00:30:00+00:00")
+        );
+        assert!(feed.warnings.is_empty(), "{:?}", feed.warnings);
+    }
+
+    #[test]
+    fn json_feed_problems_become_warnings() {
+        let source = json_feed(r#"{ "id": "1", "date_published": "yesterday" }"#)
+            .replace("https://jsonfeed.org/version/1.1", "1.1");
+        let feed = parse_feed(FeedFormat::Json, &source).unwrap();
+        assert_eq!(feed.items[0].published, None);
+        assert_eq!(feed.warnings.len(), 5, "{:?}", feed.warnings);
+    }
+
+    #[test]
+    fn json_feed_must_be_json() {
+        assert!(parse_feed(FeedFormat::Json, "<rss/>").is_err());
+        assert!(parse_feed(FeedFormat::Json, r#"{ "title": "No version" }"#).is_err());
+    }
+
+    #[test]
+    fn only_web_links_are_kept() {
+        let source = json_feed(
+            r#"{ "id": "1", "title": "A", "url": "JavaScript:alert(1)" },
+               { "id": "2", "title": "B", "url": "HTTP://blog.example.com/b" }"#,
+        )
+        .replace("https://blog.example.com/", "data:text/html,hi");
+        let feed = parse_feed(FeedFormat::Json, &source).unwrap();
+        assert_eq!(feed.link, None);
+        assert_eq!(feed.items[0].link, None);
+        assert_eq!(
+            feed.items[1].link.as_deref(),
+            Some("HTTP://blog.example.com/b")
+        );
+        assert!(feed
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("JavaScript:alert(1)")));
+    }
+}
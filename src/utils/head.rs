@@ -0,0 +1,68 @@
+use web_sys::Element;
+
+/// The blog's name, shown in the top bar and used in document titles.
+pub const SITE_TITLE: &str = "Younghyun's Blog";
+
+/// Describes pages that do not describe themselves.
+pub const SITE_DESCRIPTION: &str = "Notes on Rust, web development and the projects behind them.";
+
+/// Marks the elements this module manages, so they can be found again
+/// without touching whatever `index.html` put in the head.
+const KEY_ATTRIBUTE: &str = "data-head-key";
+
+/// Finds the head element tagged `key`, creating a `<tag>` for it if there is
+/// none yet, and sets `attributes` on it.
+pub fn upsert_head_element(tag: &str, key: &str, attributes: &[(&str, &str)]) -> Option<Element> {
+    let document = leptos::prelude::document();
+    let head = document.head()?;
+    let selector = format!("{}[{}=\"{}\"]", tag, KEY_ATTRIBUTE, key);
+    let element = match head.query_selector(&selector).ok().flatten() {
+        Some(element) => element,
+        None => {
+            let element = document.create_element(tag).ok()?;
+            element.set_attribute(KEY_ATTRIBUTE, key).ok()?;
+            head.append_child(&element).ok()?;
+            element
+        }
+    };
+    for (name, value) in attributes {
+        element.set_attribute(name, value).ok()?;
+    }
+    Some(element)
+}
+
+/// Removes the head element tagged `key`, if any.
+pub fn remove_head_element(key: &str) {
+    remove_matching(&format!("[{}=\"{}\"]", KEY_ATTRIBUTE, key));
+}
+
+/// Removes every head element whose key starts with `prefix`.
+pub fn remove_head_elements(prefix: &str) {
+    remove_matching(&format!("[{}^=\"{}\"]", KEY_ATTRIBUTE, prefix));
+}
+
+fn remove_matching(selector: &str) {
+    let Some(head) = leptos::prelude::document().head() else {
+        return;
+    };
+    let Ok(elements) = head.query_selector_all(selector) else {
+        return;
+    };
+    for index in 0..elements.length() {
+        if let Some(element) = elements.item(index) {
+            let _ = head.remove_child(&element);
+        }
+    }
+}
+
+/// Sets `<meta {attribute}="{name}" content>`, or removes it for `None`.
+/// Open Graph tags go by `property`, everything else by `name`.
+pub fn set_meta(attribute: &str, name: &str, content: Option<&str>) {
+    let key = format!("meta:{}", name);
+    match content {
+        Some(content) => {
+            upsert_head_element("meta", &key, &[(attribute, name), ("content", content)]);
+        }
+        None => remove_head_element(&key),
+    }
+}
@@ -0,0 +1,601 @@
+//! A small, table-driven syntax highlighter for the languages the blog
+//! actually uses. It only tokenizes (no parsing), which is plenty for
+//! colouring code samples and keeps the wasm bundle small.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    Toml,
+    Shell,
+    Json,
+    Sql,
+    TypeScript,
+}
+
+struct Syntax {
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    keywords: &'static [&'static str],
+    literals: &'static [&'static str],
+    types: &'static [&'static str],
+    /// Matches keywords, literals and types regardless of case (SQL).
+    ignore_case: bool,
+    /// Treats any capitalized identifier as a type name.
+    capitalized_types: bool,
+    /// Extra characters allowed inside identifiers besides `[A-Za-z0-9_]`.
+    ident_extra: &'static [char],
+}
+
+const RUST: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"'],
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+        "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "type",
+        "unsafe", "use", "where", "while", "yield",
+    ],
+    literals: &["true", "false", "None", "Some", "Ok", "Err"],
+    types: &[
+        "bool", "char", "str", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32",
+        "u64", "u128", "usize", "f32", "f64",
+    ],
+    ignore_case: false,
+    capitalized_types: true,
+    ident_extra: &[],
+};
+
+const TOML: Syntax = Syntax {
+    line_comments: &["#"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    keywords: &[],
+    literals: &["true", "false", "inf", "nan"],
+    types: &[],
+    ignore_case: false,
+    capitalized_types: false,
+    ident_extra: &['-'],
+};
+
+const SHELL: Syntax = Syntax {
+    line_comments: &["#"],
+    block_comment: None,
+    quotes: &['"', '\''],
+    keywords: &[
+        "if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case", "esac",
+        "in", "function", "return", "export", "local", "readonly", "unset", "source", "alias",
+        "exit", "break", "continue",
+    ],
+    literals: &[],
+    types: &[],
+    ignore_case: false,
+    capitalized_types: false,
+    ident_extra: &['-', '.', '/'],
+};
+
+const JSON: Syntax = Syntax {
+    line_comments: &[],
+    block_comment: None,
+    quotes: &['"'],
+    keywords: &[],
+    literals: &["true", "false", "null"],
+    types: &[],
+    ignore_case: false,
+    capitalized_types: false,
+    ident_extra: &[],
+};
+
+const SQL: Syntax = Syntax {
+    line_comments: &["--"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['\''],
+    keywords: &[
+        "select",
+        "from",
+        "where",
+        "insert",
+        "into",
+        "values",
+        "update",
+        "set",
+        "delete",
+        "create",
+        "table",
+        "alter",
+        "drop",
+        "index",
+        "primary",
+        "key",
+        "foreign",
+        "references",
+        "not",
+        "default",
+        "unique",
+        "and",
+        "or",
+        "in",
+        "is",
+        "like",
+        "ilike",
+        "join",
+        "left",
+        "right",
+        "inner",
+        "outer",
+        "full",
+        "on",
+        "as",
+        "group",
+        "by",
+        "order",
+        "asc",
+        "desc",
+        "having",
+        "limit",
+        "offset",
+        "distinct",
+        "union",
+        "all",
+        "returning",
+        "exists",
+        "case",
+        "when",
+        "then",
+        "else",
+        "end",
+        "begin",
+        "commit",
+        "rollback",
+        "transaction",
+        "if",
+        "cascade",
+        "constraint",
+        "check",
+        "with",
+        "view",
+        "trigger",
+        "function",
+        "returns",
+        "language",
+        "grant",
+        "to",
+        "conflict",
+        "do",
+        "nothing",
+    ],
+    literals: &["true", "false", "null"],
+    types: &[
+        "int",
+        "integer",
+        "bigint",
+        "smallint",
+        "serial",
+        "bigserial",
+        "text",
+        "varchar",
+        "char",
+        "boolean",
+        "bool",
+        "uuid",
+        "timestamp",
+        "timestamptz",
+        "date",
+        "time",
+        "numeric",
+        "decimal",
+        "real",
+        "json",
+        "jsonb",
+        "bytea",
+        "float",
+        "double",
+        "precision",
+    ],
+    ignore_case: true,
+    capitalized_types: false,
+    ident_extra: &[],
+};
+
+const TYPESCRIPT: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    quotes: &['"', '\'', '`'],
+    keywords: &[
+        "abstract",
+        "as",
+        "async",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "constructor",
+        "continue",
+        "debugger",
+        "declare",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "export",
+        "extends",
+        "finally",
+        "for",
+        "from",
+        "function",
+        "get",
+        "if",
+        "implements",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "keyof",
+        "let",
+        "namespace",
+        "new",
+        "of",
+        "private",
+        "protected",
+        "public",
+        "readonly",
+        "return",
+        "set",
+        "static",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "try",
+        "type",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "yield",
+    ],
+    literals: &["true", "false", "null", "undefined", "NaN", "Infinity"],
+    types: &[
+        "string", "number", "boolean", "any", "unknown", "never", "object", "bigint", "symbol",
+    ],
+    ignore_case: false,
+    capitalized_types: true,
+    ident_extra: &['$'],
+};
+
+impl Language {
+    /// Maps a fenced code block's language tag (`rust`, `rs`, `sh`, ...) to
+    /// a supported language.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        match tag.to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Some(Language::Rust),
+            "toml" => Some(Language::Toml),
+            "sh" | "bash" | "shell" | "zsh" | "console" => Some(Language::Shell),
+            "json" | "jsonc" => Some(Language::Json),
+            "sql" | "postgres" | "postgresql" | "psql" => Some(Language::Sql),
+            "ts" | "typescript" | "tsx" | "js" | "javascript" | "jsx" => Some(Language::TypeScript),
+            _ => None,
+        }
+    }
+
+    fn syntax(self) -> &'static Syntax {
+        match self {
+            Language::Rust => &RUST,
+            Language::Toml => &TOML,
+            Language::Shell => &SHELL,
+            Language::Json => &JSON,
+            Language::Sql => &SQL,
+            Language::TypeScript => &TYPESCRIPT,
+        }
+    }
+}
+
+/// Escapes text for use inside HTML elements and attribute values.
+pub fn escape_html(text: &str, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// Highlights `code` as escaped HTML, wrapping tokens in
+/// `<span class="hl-…">`. Spans never cross a line break, so the output can
+/// be split into lines safely.
+pub fn highlight(code: &str, language: Language) -> String {
+    let mut out = String::with_capacity(code.len() * 2);
+    let mut rest = code;
+    let mut line_start = true;
+
+    while !rest.is_empty() {
+        let (len, class) = next_token(rest, language, line_start);
+        let (token, tail) = rest.split_at(len);
+        push_token(&mut out, token, class);
+        // Whitespace such as "\n    " still leaves us at the start of a line.
+        line_start = match token.rfind('\n') {
+            Some(index) => token[index + 1..].trim().is_empty(),
+            None => line_start && token.trim().is_empty(),
+        };
+        rest = tail;
+    }
+
+    out
+}
+
+fn push_token(out: &mut String, token: &str, class: Option<&str>) {
+    let Some(class) = class else {
+        escape_html(token, out);
+        return;
+    };
+    for (index, line) in token.split('\n').enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        if !line.is_empty() {
+            out.push_str("<span class=\"hl-");
+            out.push_str(class);
+            out.push_str("\">");
+            escape_html(line, out);
+            out.push_str("</span>");
+        }
+    }
+}
+
+fn is_ident_char(c: char, syntax: &Syntax) -> bool {
+    c.is_alphanumeric() || c == '_' || syntax.ident_extra.contains(&c)
+}
+
+fn contains_word(words: &[&str], word: &str, ignore_case: bool) -> bool {
+    if ignore_case {
+        words
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(word))
+    } else {
+        words.contains(&word)
+    }
+}
+
+/// Byte length of the text up to and including `close`, or of all of `text`
+/// if it is never closed.
+fn until(text: &str, from: usize, close: &str) -> usize {
+    text[from..]
+        .find(close)
+        .map_or(text.len(), |index| from + index + close.len())
+}
+
+/// Byte length of a string literal opened by `quote`, honouring backslash
+/// escapes.
+fn string_len(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (index, c) in text.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return index + c.len_utf8();
+        }
+    }
+    text.len()
+}
+
+/// The first non-blank character after `len` bytes of `text`.
+fn next_non_blank(text: &str, len: usize) -> Option<char> {
+    text[len..].chars().find(|c| *c != ' ' && *c != '\t')
+}
+
+/// Splits off the next token: its byte length and `hl-` class, if any.
+fn next_token(text: &str, language: Language, line_start: bool) -> (usize, Option<&'static str>) {
+    let syntax = language.syntax();
+    let first = text
+        .chars()
+        .next()
+        .expect("next_token called on empty text");
+
+    if first.is_whitespace() {
+        let len = text
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(text.len());
+        return (len, None);
+    }
+
+    // Shell variables come before comments so `$#` is not a comment.
+    if language == Language::Shell && first == '$' {
+        if text[1..].starts_with('{') {
+            return (until(text, 1, "}"), Some("variable"));
+        }
+        let len = 1 + text[1..]
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(text.len() - 1);
+        let len = if len == 1 {
+            text[1..].chars().next().map_or(1, |c| 1 + c.len_utf8())
+        } else {
+            len
+        };
+        return (len, Some("variable"));
+    }
+
+    if let Some(prefix) = syntax
+        .line_comments
+        .iter()
+        .find(|prefix| text.starts_with(**prefix))
+    {
+        let len = text[prefix.len()..]
+            .find('\n')
+            .map_or(text.len(), |index| prefix.len() + index);
+        return (len, Some("comment"));
+    }
+    if let Some((open, close)) = syntax.block_comment {
+        if text.starts_with(open) {
+            return (until(text, open.len(), close), Some("comment"));
+        }
+    }
+
+    match language {
+        Language::Rust if text.starts_with("#[") || text.starts_with("#![") => {
+            let mut depth = 0;
+            for (index, c) in text.char_indices() {
+                match c {
+                    '[' => depth += 1,
+                    ']' if depth == 1 => return (index + 1, Some("attribute")),
+                    ']' => depth -= 1,
+                    '\n' => return (index, Some("attribute")),
+                    _ => {}
+                }
+            }
+            return (text.len(), Some("attribute"));
+        }
+        Language::Rust if first == 'r' && (text.starts_with("r\"") || text.starts_with("r#")) => {
+            let hashes = text[1..].chars().take_while(|c| *c == '#').count();
+            if text[1 + hashes..].starts_with('"') {
+                let close = format!("\"{}", "#".repeat(hashes));
+                return (until(text, 2 + hashes, &close), Some("string"));
+            }
+        }
+        Language::Rust if first == '\'' => {
+            // A char literal (`'a'`, `'\n'`) or a lifetime (`'a`).
+            let mut chars = text.chars().skip(1);
+            let is_char = matches!(
+                (chars.next(), chars.next()),
+                (Some('\\'), _) | (Some(_), Some('\''))
+            );
+            if is_char {
+                return (string_len(text, '\''), Some("string"));
+            }
+            let len = 1 + text[1..]
+                .find(|c: char| !is_ident_char(c, syntax))
+                .unwrap_or(text.len() - 1);
+            return (len, Some("lifetime"));
+        }
+        Language::Toml if line_start && first == '[' => {
+            let len = text.find('\n').unwrap_or(text.len());
+            let len = text[..len].rfind(']').map_or(len, |index| index + 1);
+            return (len, Some("section"));
+        }
+        _ => {}
+    }
+
+    if syntax.quotes.contains(&first) {
+        let triple: String = std::iter::repeat_n(first, 3).collect();
+        let len = if text.starts_with(&triple) {
+            until(text, 3, &triple)
+        } else {
+            string_len(text, first)
+        };
+        let class = if language == Language::Json && next_non_blank(text, len) == Some(':') {
+            "key"
+        } else {
+            "string"
+        };
+        return (len, Some(class));
+    }
+
+    if first.is_ascii_digit() {
+        let mut len = 0;
+        let mut chars = text.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            let continues = c.is_ascii_alphanumeric()
+                || c == '_'
+                // `1.5` but not the range in `0..10` or a method call.
+                || (c == '.'
+                    && chars
+                        .peek()
+                        .is_some_and(|(_, next)| next.is_ascii_digit()));
+            if !continues {
+                break;
+            }
+            len = index + c.len_utf8();
+        }
+        return (len, Some("number"));
+    }
+
+    if is_ident_char(first, syntax) {
+        let len = text
+            .find(|c: char| !is_ident_char(c, syntax))
+            .unwrap_or(text.len());
+        let word = &text[..len];
+        let next = text[len..].chars().next();
+
+        let class = if contains_word(syntax.keywords, word, syntax.ignore_case) {
+            Some("keyword")
+        } else if contains_word(syntax.literals, word, syntax.ignore_case) {
+            Some("literal")
+        } else if contains_word(syntax.types, word, syntax.ignore_case) {
+            Some("type")
+        } else if language == Language::Rust && next == Some('!') {
+            return (len + 1, Some("macro"));
+        } else if language == Language::Toml
+            && line_start
+            && matches!(next_non_blank(text, len), Some('=') | Some('.'))
+        {
+            Some("key")
+        } else if next == Some('(') && language != Language::Shell {
+            Some("function")
+        } else if syntax.capitalized_types && first.is_uppercase() {
+            Some("type")
+        } else {
+            None
+        };
+        return (len, class);
+    }
+
+    (first.len_utf8(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_indented_toml_keys_and_sections() {
+        let html = highlight(
+            "[package]\n  name = \"demo\"\n  [dependencies.serde]\n",
+            Language::Toml,
+        );
+        assert!(
+            html.contains("<span class=\"hl-key\">name</span>"),
+            "{}",
+            html
+        );
+        assert!(
+            html.contains("<span class=\"hl-section\">[dependencies.serde]</span>"),
+            "{}",
+            html
+        );
+    }
+
+    #[test]
+    fn text_after_a_multiline_token_is_not_a_line_start() {
+        let html = highlight("a = \"\"\"x\ny\"\"\" b = 1\n", Language::Toml);
+        assert!(!html.contains("hl-key\">b<"), "{}", html);
+    }
+
+    #[test]
+    fn spans_never_cross_line_breaks() {
+        let html = highlight("/* one\ntwo */ fn main() {}", Language::Rust);
+        for line in html.split('\n') {
+            assert_eq!(
+                line.matches("<span").count(),
+                line.matches("</span>").count(),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn escapes_html() {
+        let html = highlight("let s = \"<script>\";", Language::Rust);
+        assert!(!html.contains("<script>"), "{}", html);
+        assert!(html.contains("&lt;script&gt;"), "{}", html);
+    }
+}
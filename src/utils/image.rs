@@ -0,0 +1,82 @@
+use leptos::prelude::{document, window};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    js_sys::Promise,
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+    Blob, CanvasRenderingContext2d, File, HtmlCanvasElement, ImageBitmap,
+};
+
+/// Quality used when re-encoding a downscaled image as JPEG.
+const JPEG_QUALITY: f64 = 0.9;
+
+/// Shrinks `file` so its longer side is at most `max_dimension` pixels,
+/// redrawing it on an offscreen canvas. Images that already fit, GIFs (which
+/// would lose their animation) and SVGs come back unchanged.
+///
+/// PNG and WebP keep their format so transparency survives; anything else is
+/// re-encoded as JPEG.
+pub async fn downscale_image(file: &File, max_dimension: u32) -> Result<Blob, JsValue> {
+    let mime_type = file.type_();
+    if matches!(mime_type.as_str(), "image/gif" | "image/svg+xml") {
+        return Ok(file.clone().into());
+    }
+
+    let bitmap: ImageBitmap = JsFuture::from(window().create_image_bitmap_with_blob(file)?)
+        .await?
+        .unchecked_into();
+    let (width, height) = (bitmap.width(), bitmap.height());
+    let longest = width.max(height);
+    if longest <= max_dimension {
+        bitmap.close();
+        return Ok(file.clone().into());
+    }
+
+    let scale = f64::from(max_dimension) / f64::from(longest);
+    let target_width = ((f64::from(width) * scale).round() as u32).max(1);
+    let target_height = ((f64::from(height) * scale).round() as u32).max(1);
+
+    let canvas: HtmlCanvasElement = document().create_element("canvas")?.unchecked_into();
+    canvas.set_width(target_width);
+    canvas.set_height(target_height);
+    let context: CanvasRenderingContext2d = canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("2d canvas context unavailable"))?
+        .unchecked_into();
+    let drawn = context.draw_image_with_image_bitmap_and_dw_and_dh(
+        &bitmap,
+        0.0,
+        0.0,
+        f64::from(target_width),
+        f64::from(target_height),
+    );
+    bitmap.close();
+    drawn?;
+
+    let output_type = match mime_type.as_str() {
+        "image/png" | "image/webp" => mime_type.as_str(),
+        _ => "image/jpeg",
+    };
+    canvas_to_blob(&canvas, output_type).await
+}
+
+/// Promise-based wrapper around the callback-based `canvas.toBlob()`.
+async fn canvas_to_blob(canvas: &HtmlCanvasElement, mime_type: &str) -> Result<Blob, JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let on_encoded_reject = reject.clone();
+        let on_encoded = Closure::once_into_js(move |blob: JsValue| {
+            let _ = if blob.is_null() {
+                on_encoded_reject.call1(&JsValue::NULL, &JsValue::from_str("encoding failed"))
+            } else {
+                resolve.call1(&JsValue::NULL, &blob)
+            };
+        });
+        if let Err(err) = canvas.to_blob_with_type_and_encoder_options(
+            on_encoded.unchecked_ref(),
+            mime_type,
+            &JsValue::from_f64(JPEG_QUALITY),
+        ) {
+            let _ = reject.call1(&JsValue::NULL, &err);
+        }
+    });
+    Ok(JsFuture::from(promise).await?.unchecked_into())
+}
@@ -0,0 +1,39 @@
+/// Schemes a backend-supplied URL may use to end up in an `href` or `src`.
+const LINK_SCHEMES: [&str; 3] = ["https://", "http://", "mailto:"];
+
+/// Whether `url` is safe to render as a link or image source. Anything but
+/// the schemes above, `javascript:` and `data:` included, is rejected.
+pub fn is_safe_link(url: &str) -> bool {
+    let url = url.trim_start().to_ascii_lowercase();
+    LINK_SCHEMES.iter().any(|scheme| url.starts_with(scheme))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn web_and_mail_links_are_safe() {
+        for safe in [
+            "https://example.com",
+            "HTTP://example.com/a?b#c",
+            " mailto:me@example.com",
+        ] {
+            assert!(is_safe_link(safe), "{}", safe);
+        }
+    }
+
+    #[test]
+    fn other_schemes_are_rejected() {
+        for unsafe_link in [
+            "javascript:alert(1)",
+            "JavaScript:alert(1)",
+            "java\tscript:alert(1)",
+            "data:text/html,hi",
+            "vbscript:msgbox",
+            "",
+        ] {
+            assert!(!is_safe_link(unsafe_link), "{:?}", unsafe_link);
+        }
+    }
+}
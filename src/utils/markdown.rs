@@ -0,0 +1,344 @@
+use std::collections::{HashMap, HashSet};
+
+use pulldown_cmark::{html, CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd};
+
+use crate::utils::highlight::{escape_html, highlight, Language};
+
+/// Info-string flag that numbers a code block's lines: ```` ```rust,line-numbers ````.
+const LINE_NUMBERS_FLAG: &str = "line-numbers";
+
+/// One heading of a rendered document, for building a table of contents.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TocEntry {
+    pub level: u8,
+    pub id: String,
+    pub title: String,
+}
+
+/// Sanitized HTML plus the headings it contains, in document order.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct RenderedMarkdown {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+}
+
+fn markdown_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+}
+
+/// Turns text into a URL segment or fragment: lowercase alphanumerics joined
+/// by single dashes, e.g. "Why Rust?" -> "why-rust". Empty if `text` has no
+/// alphanumerics at all.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if (c.is_whitespace() || c == '-' || c == '_') && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_owned()
+}
+
+/// Returns `base`, or `base-2`, `base-3`, ... if it was handed out already.
+fn unique_id(base: String, used: &mut HashSet<String>) -> String {
+    let mut id = base.clone();
+    let mut counter = 2;
+    while used.contains(&id) {
+        id = format!("{}-{}", base, counter);
+        counter += 1;
+    }
+    used.insert(id.clone());
+    id
+}
+
+/// Gives every heading a unique `id` and a trailing `#` anchor link, and
+/// records it in the table of contents.
+fn anchor_headings<'a>(events: Vec<Event<'a>>, toc: &mut Vec<TocEntry>) -> Vec<Event<'a>> {
+    let mut used = HashSet::new();
+    let mut output = Vec::with_capacity(events.len());
+    let mut index = 0;
+
+    while index < events.len() {
+        let Event::Start(Tag::Heading {
+            level,
+            classes,
+            attrs,
+            ..
+        }) = &events[index]
+        else {
+            output.push(events[index].clone());
+            index += 1;
+            continue;
+        };
+
+        // Collect the heading's text to derive its id.
+        let end = events[index..]
+            .iter()
+            .position(|event| matches!(event, Event::End(TagEnd::Heading(_))))
+            .map_or(events.len(), |offset| index + offset);
+        let title: String = events[index + 1..end]
+            .iter()
+            .filter_map(|event| match event {
+                Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                _ => None,
+            })
+            .collect();
+        let slug = slugify(&title);
+        let base = if slug.is_empty() {
+            String::from("section")
+        } else {
+            slug
+        };
+        let id = unique_id(base, &mut used);
+
+        output.push(Event::Start(Tag::Heading {
+            level: *level,
+            id: Some(CowStr::from(id.clone())),
+            classes: classes.clone(),
+            attrs: attrs.clone(),
+        }));
+        output.extend(events[index + 1..end].iter().cloned());
+        output.push(Event::Html(CowStr::from(format!(
+            r##"<a class="heading-anchor" href="#{}">#</a>"##,
+            id
+        ))));
+        if let Some(end_event) = events.get(end) {
+            output.push(end_event.clone());
+        }
+
+        toc.push(TocEntry {
+            level: *level as u8,
+            id,
+            title,
+        });
+        index = end + 1;
+    }
+
+    output
+}
+
+/// Replaces each code block with highlighted HTML inside a `.code-block`
+/// wrapper carrying a copy button. The button is wired up by `MarkdownBody`.
+fn highlight_code_blocks<'a>(events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+    let mut output = Vec::with_capacity(events.len());
+    let mut block: Option<(String, String)> = None;
+
+    for event in events {
+        match (event, block.as_mut()) {
+            (Event::Start(Tag::CodeBlock(kind)), None) => {
+                let info = match kind {
+                    CodeBlockKind::Fenced(info) => info.to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                block = Some((info, String::new()));
+            }
+            (Event::Text(text), Some((_, code))) => code.push_str(&text),
+            (Event::End(TagEnd::CodeBlock), Some(_)) => {
+                let (info, code) = block.take().expect("code block is open");
+                output.push(Event::Html(CowStr::from(render_code_block(&info, &code))));
+            }
+            (event, _) => output.push(event),
+        }
+    }
+
+    output
+}
+
+fn render_code_block(info: &str, code: &str) -> String {
+    let mut tag = "";
+    let mut line_numbers = false;
+    for word in info.split(|c: char| c == ',' || c.is_whitespace()) {
+        if word == LINE_NUMBERS_FLAG {
+            line_numbers = true;
+        } else if tag.is_empty() {
+            tag = word;
+        }
+    }
+
+    let code = code.strip_suffix('\n').unwrap_or(code);
+    let body = match Language::from_tag(tag) {
+        Some(language) => highlight(code, language),
+        None => {
+            let mut escaped = String::with_capacity(code.len());
+            escape_html(code, &mut escaped);
+            escaped
+        }
+    };
+
+    let mut html = String::with_capacity(body.len() + 256);
+    html.push_str(
+        r#"<div class="code-block"><div class="code-toolbar"><span class="code-language">"#,
+    );
+    escape_html(tag, &mut html);
+    html.push_str(r#"</span><button type="button" class="code-copy">Copy</button></div><pre"#);
+    if line_numbers {
+        html.push_str(r#" class="line-numbers""#);
+    }
+    html.push_str("><code");
+    if !tag.is_empty() {
+        html.push_str(r#" class="language-"#);
+        escape_html(tag, &mut html);
+        html.push('"');
+    }
+    html.push('>');
+    for line in body.split('\n') {
+        html.push_str(r#"<span class="code-line">"#);
+        html.push_str(line);
+        html.push_str("</span>\n");
+    }
+    html.push_str("</code></pre></div>\n");
+    html
+}
+
+/// Whitelist applied to the generated HTML. Raw HTML in the Markdown source
+/// passes through the same filter, so scripts, event handlers and
+/// `javascript:` URLs never reach the page.
+fn sanitizer() -> ammonia::Builder<'static> {
+    let mut builder = ammonia::Builder::default();
+    builder
+        .add_generic_attributes(["id"])
+        .add_tags(["input", "button"])
+        .add_tag_attributes("input", ["type", "checked", "disabled"])
+        .add_tag_attributes("button", ["type", "class"])
+        .add_tag_attributes("a", ["class"])
+        .add_tag_attributes("code", ["class"])
+        .add_tag_attributes("div", ["class"])
+        .add_tag_attributes("pre", ["class"])
+        .add_tag_attributes("span", ["class"])
+        .add_tag_attributes("sup", ["class"]);
+    builder
+}
+
+/// Renders Markdown (CommonMark plus tables, footnotes, strikethrough and
+/// task lists) to sanitized HTML with anchored headings and highlighted
+/// code blocks.
+pub fn render_markdown(source: &str) -> RenderedMarkdown {
+    let events: Vec<Event> = Parser::new_ext(source, markdown_options()).collect();
+    let mut toc = Vec::new();
+    let events = highlight_code_blocks(anchor_headings(events, &mut toc));
+
+    let mut unsafe_html = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    RenderedMarkdown {
+        html: sanitizer().clean(&unsafe_html).to_string(),
+        toc,
+    }
+}
+
+/// The tighter whitelist for comments: text formatting and links only.
+fn lite_sanitizer() -> ammonia::Builder<'static> {
+    let mut builder = ammonia::Builder::default();
+    builder
+        .tags(HashSet::from([
+            "p",
+            "br",
+            "strong",
+            "em",
+            "del",
+            "code",
+            "pre",
+            "a",
+            "ul",
+            "ol",
+            "li",
+            "blockquote",
+        ]))
+        .generic_attributes(HashSet::new())
+        .tag_attributes(HashMap::from([("a", HashSet::from(["href"]))]))
+        .url_schemes(HashSet::from(["http", "https", "mailto"]))
+        .link_rel(Some("nofollow noopener noreferrer ugc"));
+    builder
+}
+
+/// Renders the small Markdown subset allowed in comments: emphasis,
+/// strikethrough, code, links, lists and quotes. Headings flatten to
+/// paragraphs, images turn into links and raw HTML shows as plain text.
+pub fn render_markdown_lite(source: &str) -> String {
+    let events = Parser::new_ext(source, Options::ENABLE_STRIKETHROUGH).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::Heading { .. }) => Event::Start(Tag::Paragraph),
+        Event::End(TagEnd::Heading(_)) => Event::End(TagEnd::Paragraph),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }),
+        Event::End(TagEnd::Image) => Event::End(TagEnd::Link),
+        event => event,
+    });
+
+    let mut unsafe_html = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, events);
+    lite_sanitizer().clean(&unsafe_html).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_scripts_handlers_and_javascript_urls() {
+        let html = render_markdown(
+            "<script>alert(1)</script>\n\n<img src=x onerror=alert(1)>\n\n[link](javascript:alert(1))",
+        )
+        .html;
+        assert!(!html.contains("<script"), "{}", html);
+        assert!(!html.contains("onerror"), "{}", html);
+        assert!(!html.contains("javascript:"), "{}", html);
+    }
+
+    #[test]
+    fn keeps_heading_anchors_and_highlighting() {
+        let rendered = render_markdown("# Hello World\n\n```rust\nfn main() {}\n```\n");
+        assert!(
+            rendered.html.contains("id=\"hello-world\""),
+            "{}",
+            rendered.html
+        );
+        assert!(
+            rendered.html.contains("heading-anchor"),
+            "{}",
+            rendered.html
+        );
+        assert!(rendered.html.contains("hl-keyword"), "{}", rendered.html);
+        assert_eq!(rendered.toc.len(), 1);
+    }
+
+    #[test]
+    fn lite_renders_raw_html_as_text() {
+        let html = render_markdown_lite(
+            "<b onclick=x>hi</b> [a](javascript:alert(1)) [b](https://example.com)",
+        );
+        assert!(html.contains("&lt;b onclick=x&gt;"), "{}", html);
+        assert!(!html.contains("javascript:"), "{}", html);
+        assert!(
+            html.contains("rel=\"nofollow noopener noreferrer ugc\""),
+            "{}",
+            html
+        );
+    }
+
+    #[test]
+    fn lite_flattens_headings_and_images() {
+        let html = render_markdown_lite("# Title\n\n![alt](https://example.com/x.png)");
+        assert!(!html.contains("<h1"), "{}", html);
+        assert!(!html.contains("<img"), "{}", html);
+        assert!(
+            html.contains("<a href=\"https://example.com/x.png\""),
+            "{}",
+            html
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use leptos::prelude::*;
+
+use crate::utils::validation::{character_classes, Field};
+
+/// Frequently breached passwords, one per line, bundled into the wasm binary.
+const COMMON_PASSWORDS: &str = include_str!("./common_passwords.txt");
+
+/// Lowest score the signup and password change forms accept.
+pub const MIN_ACCEPTABLE_SCORE: u8 = 2;
+
+/// Result of rating a password, from 0 (trivially guessable) to 4 (strong).
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct PasswordStrength {
+    pub score: u8,
+    pub suggestions: Vec<&'static str>,
+}
+
+impl PasswordStrength {
+    pub fn label(&self) -> &'static str {
+        match self.score {
+            0 => "Very weak",
+            1 => "Weak",
+            2 => "Fair",
+            3 => "Good",
+            _ => "Strong",
+        }
+    }
+
+    pub fn color(&self) -> &'static str {
+        match self.score {
+            0 => "#ff4d4d",
+            1 => "#ff944d",
+            2 => "#ffd24d",
+            3 => "#9be36b",
+            _ => "#4dcf6b",
+        }
+    }
+
+    pub fn is_acceptable(&self) -> bool {
+        self.score >= MIN_ACCEPTABLE_SCORE
+    }
+}
+
+fn is_common(password: &str) -> bool {
+    let lowered = password.to_lowercase();
+    COMMON_PASSWORDS.lines().any(|common| common == lowered)
+}
+
+/// True if the password contains any of `related` (name, email local part, ...),
+/// ignoring case. Very short values are skipped so "Al" doesn't flag everything.
+fn contains_personal_info(password: &str, related: &[String]) -> bool {
+    let lowered = password.to_lowercase();
+    related
+        .iter()
+        .map(|value| value.trim().to_lowercase())
+        .filter(|value| value.chars().count() >= 3)
+        .any(|value| lowered.contains(&value))
+}
+
+/// Rates `password`. `related` holds values the user has typed elsewhere in
+/// the form, such as their user name and email, which should not appear in it.
+pub fn evaluate(password: &str, related: &[String]) -> PasswordStrength {
+    if password.is_empty() {
+        return PasswordStrength::default();
+    }
+
+    let mut suggestions = Vec::new();
+    let length = password.chars().count();
+
+    let mut score: i32 = match length {
+        0..=7 => 0,
+        8..=11 => 1,
+        12..=15 => 2,
+        _ => 3,
+    };
+    if length < 12 {
+        suggestions.push("Longer passwords are much harder to guess.");
+    }
+
+    if character_classes(password) >= 3 {
+        score += 1;
+    } else {
+        suggestions.push("Mix lowercase, uppercase, digits and symbols.");
+    }
+
+    // Few distinct characters ("aaaaaaaa1", "abababab") barely add entropy.
+    let mut distinct: Vec<char> = password.chars().collect();
+    distinct.sort_unstable();
+    distinct.dedup();
+    if distinct.len() * 3 < length + 3 {
+        score -= 1;
+        suggestions.push("Avoid repeating the same characters.");
+    }
+
+    if contains_personal_info(password, related) {
+        score = score.min(1);
+        suggestions.push("Don't include your name or email.");
+    }
+
+    if is_common(password) {
+        score = 0;
+        suggestions.push("This is one of the most common passwords.");
+    }
+
+    PasswordStrength {
+        score: score.clamp(0, 4) as u8,
+        suggestions,
+    }
+}
+
+/// Reactively rates `password` against whatever `related` currently returns.
+pub fn use_password_strength(
+    password: Field,
+    related: impl Fn() -> Vec<String> + Send + Sync + 'static,
+) -> Memo<PasswordStrength> {
+    Memo::new(move |_| evaluate(&password.value.get(), &related()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_password_scores_zero_without_suggestions() {
+        assert_eq!(evaluate("", &[]), PasswordStrength::default());
+    }
+
+    #[test]
+    fn long_mixed_passwords_are_acceptable() {
+        let strength = evaluate("Correct-Horse-42-Battery", &[]);
+        assert_eq!(strength.score, 4);
+        assert!(strength.is_acceptable());
+    }
+
+    #[test]
+    fn common_passwords_score_zero() {
+        assert_eq!(evaluate("Password", &[]).score, 0);
+    }
+
+    #[test]
+    fn repeated_characters_lower_the_score() {
+        assert!(
+            evaluate("aaaaaaaaaaaaaaaA1!", &[]).score < evaluate("qz8Lw2mPx7Rt4vNk!", &[]).score
+        );
+    }
+
+    #[test]
+    fn personal_info_caps_the_score() {
+        let related = ["younghyun".to_owned(), "Al".to_owned()];
+        assert!(evaluate("Younghyun-2024-Secret!", &related).score <= 1);
+        // Too short to count as personal info.
+        assert!(evaluate("Alpine-Meadow-2024!", &related).score > 1);
+    }
+}
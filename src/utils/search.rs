@@ -0,0 +1,108 @@
+/// The distinct words of a search query, lowercased, without surrounding
+/// quotes. Longest first, so overlapping terms highlight the longer match.
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in query.split_whitespace() {
+        let term = word.trim_matches(|c| c == '"' || c == '\'').to_lowercase();
+        if !term.is_empty() && !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms.sort_by_key(|term| std::cmp::Reverse(term.chars().count()));
+    terms
+}
+
+/// Byte length of the prefix of `text` that equals `term`, ignoring case.
+fn match_len(text: &str, term: &str) -> Option<usize> {
+    let mut text_chars = text.char_indices();
+    for term_char in term.chars() {
+        let (_, text_char) = text_chars.next()?;
+        if !text_char.to_lowercase().eq(term_char.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(text_chars.next().map_or(text.len(), |(index, _)| index))
+}
+
+/// Splits `text` into runs, flagging the ones that match a term so they can
+/// be wrapped in `<mark>`. `terms` should come from `query_terms`.
+pub fn highlight_segments(text: &str, terms: &[String]) -> Vec<(String, bool)> {
+    let mut segments: Vec<(String, bool)> = Vec::new();
+    let mut plain_start = 0;
+    let mut index = 0;
+
+    while index < text.len() {
+        let rest = &text[index..];
+        match terms.iter().find_map(|term| match_len(rest, term)) {
+            Some(len) if len > 0 => {
+                if plain_start < index {
+                    segments.push((text[plain_start..index].to_owned(), false));
+                }
+                segments.push((rest[..len].to_owned(), true));
+                index += len;
+                plain_start = index;
+            }
+            _ => {
+                index += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+    if plain_start < text.len() {
+        segments.push((text[plain_start..].to_owned(), false));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marked(segments: &[(String, bool)]) -> Vec<&str> {
+        segments
+            .iter()
+            .filter(|(_, is_match)| *is_match)
+            .map(|(text, _)| text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn terms_are_distinct_unquoted_and_longest_first() {
+        assert_eq!(
+            query_terms(r#"  Rust "rustacean" rust '' "#),
+            vec!["rustacean", "rust"]
+        );
+        assert!(query_terms("   ").is_empty());
+    }
+
+    #[test]
+    fn segments_cover_the_whole_text() {
+        let text = "Leptos and leptos_router";
+        let segments = highlight_segments(text, &query_terms("LEPTOS"));
+        let joined: String = segments.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(joined, text);
+        assert_eq!(marked(&segments), vec!["Leptos", "leptos"]);
+    }
+
+    #[test]
+    fn longer_terms_win_over_their_prefixes() {
+        let segments = highlight_segments("rustacean rust", &query_terms("rust rustacean"));
+        assert_eq!(marked(&segments), vec!["rustacean", "rust"]);
+    }
+
+    #[test]
+    fn matching_keeps_multibyte_text_intact() {
+        let segments = highlight_segments("한글 Ünïcode", &query_terms("글 ünï"));
+        let joined: String = segments.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(joined, "한글 Ünïcode");
+        assert_eq!(marked(&segments), vec!["글", "Ünï"]);
+    }
+
+    #[test]
+    fn no_terms_leave_the_text_plain() {
+        assert_eq!(
+            highlight_segments("plain", &[]),
+            vec![("plain".to_owned(), false)]
+        );
+        assert!(highlight_segments("", &query_terms("x")).is_empty());
+    }
+}
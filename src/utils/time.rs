@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use leptos::prelude::*;
+
+/// A signal holding the current time, refreshed every second for as long as
+/// the calling component is mounted. Drives live countdowns.
+pub fn use_clock() -> ReadSignal<DateTime<Utc>> {
+    let (now, set_now) = signal(Utc::now());
+    if let Ok(handle) =
+        set_interval_with_handle(move || set_now.set(Utc::now()), Duration::from_secs(1))
+    {
+        on_cleanup(move || handle.clear());
+    }
+    now
+}
+
+/// Formats the time left until `deadline` as `HH:MM:SS`, or `None` once it has passed.
+pub fn format_remaining(deadline: DateTime<Utc>, now: DateTime<Utc>) -> Option<String> {
+    let remaining = (deadline - now).num_seconds();
+    if remaining <= 0 {
+        return None;
+    }
+    Some(format!(
+        "{:02}:{:02}:{:02}",
+        remaining / 3600,
+        (remaining % 3600) / 60,
+        remaining % 60
+    ))
+}
+
+/// Describes how long ago `then` was: "just now", "5 minutes ago",
+/// "yesterday", ... and the plain date once it is over a week old.
+pub fn format_relative(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let ago = |count: i64, unit: &str| {
+        format!(
+            "{} {}{} ago",
+            count,
+            unit,
+            if count == 1 { "" } else { "s" }
+        )
+    };
+    let elapsed = now - then;
+    let seconds = elapsed.num_seconds();
+    if seconds < 60 {
+        "just now".to_owned()
+    } else if seconds < 3600 {
+        ago(elapsed.num_minutes(), "minute")
+    } else if seconds < 86400 {
+        ago(elapsed.num_hours(), "hour")
+    } else if elapsed.num_days() == 1 {
+        "yesterday".to_owned()
+    } else if elapsed.num_days() < 7 {
+        ago(elapsed.num_days(), "day")
+    } else {
+        then.with_timezone(&Local).format("%Y-%m-%d").to_string()
+    }
+}
+
+/// Formats a span of months such as "Mar 2021 – Jun 2023", collapsing to a
+/// single month when both ends fall in it. An open end reads "present".
+pub fn format_period(start: NaiveDate, end: Option<NaiveDate>) -> String {
+    let month = |date: NaiveDate| date.format("%b %Y").to_string();
+    match end {
+        Some(end) if month(end) == month(start) => month(end),
+        Some(end) => format!("{} – {}", month(start), month(end)),
+        None => format!("{} – present", month(start)),
+    }
+}
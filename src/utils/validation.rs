@@ -0,0 +1,261 @@
+use leptos::prelude::*;
+
+use crate::dto::api_response::FieldError;
+
+/// A single check a form field's value must pass.
+#[derive(Clone, Debug)]
+pub enum Rule {
+    /// Must not be empty or whitespace.
+    Required,
+    /// Must look like `local@domain.tld`.
+    Email,
+    /// At least this many characters.
+    MinLength(usize),
+    /// At most this many characters.
+    MaxLength(usize),
+    /// Must mix at least three of: lowercase, uppercase, digits, symbols.
+    PasswordStrength,
+    /// A `<select>` value; the empty placeholder option does not count.
+    SelectedOption,
+    /// Must equal the current value of another field, e.g. a password confirmation.
+    Matches(RwSignal<String>),
+    /// A URL segment: lowercase letters, digits and single dashes, e.g. `hello-world`.
+    Slug,
+}
+
+impl Rule {
+    /// Returns the message to show when `value` breaks this rule.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        match self {
+            Rule::Required if value.trim().is_empty() => Err("This field is required.".to_owned()),
+            Rule::Email if !value.is_empty() && !is_email(value) => {
+                Err("Enter a valid email address.".to_owned())
+            }
+            Rule::MinLength(min) if !value.is_empty() && value.chars().count() < *min => {
+                Err(format!("Must be at least {} characters.", min))
+            }
+            Rule::MaxLength(max) if value.chars().count() > *max => {
+                Err(format!("Must be at most {} characters.", max))
+            }
+            Rule::PasswordStrength if !value.is_empty() && character_classes(value) < 3 => {
+                Err("Use at least three of: lowercase, uppercase, digits, symbols.".to_owned())
+            }
+            Rule::SelectedOption if value.is_empty() || value == "0" => {
+                Err("Please select an option.".to_owned())
+            }
+            Rule::Matches(other) if other.with(|other| other != value) => {
+                Err("Values do not match.".to_owned())
+            }
+            Rule::Slug if !value.is_empty() && !is_slug(value) => {
+                Err("Use lowercase letters, digits and single dashes.".to_owned())
+            }
+            // `/posts/new` is the editor, so no post can live there.
+            Rule::Slug if value == "new" => Err("This slug is reserved.".to_owned()),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn is_email(value: &str) -> bool {
+    if value.chars().any(char::is_whitespace) {
+        return false;
+    }
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain
+            .split_once('.')
+            .is_some_and(|(name, tld)| !name.is_empty() && !tld.is_empty())
+        && !domain.ends_with('.')
+}
+
+fn is_slug(value: &str) -> bool {
+    !value.starts_with('-')
+        && !value.ends_with('-')
+        && !value.contains("--")
+        && value
+            .chars()
+            .all(|c| c == '-' || (c.is_alphanumeric() && !c.is_uppercase()))
+}
+
+/// How many of lowercase, uppercase, digit and symbol characters appear in `value`.
+pub fn character_classes(value: &str) -> usize {
+    [
+        value.chars().any(|c| c.is_lowercase()),
+        value.chars().any(|c| c.is_uppercase()),
+        value.chars().any(|c| c.is_ascii_digit()),
+        value.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .into_iter()
+    .filter(|present| *present)
+    .count()
+}
+
+/// A form field bound to a signal, validated against a list of rules.
+///
+/// Errors are only shown once the field has been touched, so an empty form
+/// does not open covered in red. Errors reported by the backend for this
+/// field take precedence until the user edits the value again.
+#[derive(Clone, Copy)]
+pub struct Field {
+    pub value: RwSignal<String>,
+    rules: StoredValue<Vec<Rule>>,
+    touched: RwSignal<bool>,
+    server_error: RwSignal<Option<String>>,
+}
+
+impl Field {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        Self {
+            value: RwSignal::new(String::new()),
+            rules: StoredValue::new(rules),
+            touched: RwSignal::new(false),
+            server_error: RwSignal::new(None),
+        }
+    }
+
+    /// Updates the value from user input.
+    pub fn set(&self, value: String) {
+        self.value.set(value);
+        self.touched.set(true);
+        self.server_error.set(None);
+    }
+
+    /// Replaces the value programmatically (prefill, rollback) without
+    /// marking the field as touched.
+    pub fn reset(&self, value: String) {
+        self.value.set(value);
+        self.touched.set(false);
+        self.server_error.set(None);
+    }
+
+    /// Marks the field as visited, e.g. on blur, so its error becomes visible.
+    pub fn touch(&self) {
+        self.touched.set(true);
+    }
+
+    /// The first error for the current value, whether touched or not.
+    pub fn error(&self) -> Option<String> {
+        if let Some(error) = self.server_error.get() {
+            return Some(error);
+        }
+        let value = self.value.get();
+        self.rules
+            .with_value(|rules| rules.iter().find_map(|rule| rule.check(&value).err()))
+    }
+
+    /// The error to render next to the field right now.
+    pub fn visible_error(&self) -> Option<String> {
+        if self.touched.get() {
+            self.error()
+        } else {
+            None
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.error().is_none()
+    }
+
+    fn set_server_error(&self, message: String) {
+        self.touched.set(true);
+        self.server_error.set(Some(message));
+    }
+}
+
+/// Attaches backend field errors to the matching fields, keyed by the
+/// request's field names. Returns the errors that matched no field.
+pub fn apply_field_errors(fields: &[(&str, Field)], errors: &[FieldError]) -> Vec<FieldError> {
+    errors
+        .iter()
+        .filter(
+            |error| match fields.iter().find(|(name, _)| *name == error.field) {
+                Some((_, field)) => {
+                    field.set_server_error(error.message.clone());
+                    false
+                }
+                None => true,
+            },
+        )
+        .cloned()
+        .collect()
+}
+
+/// The rules for a user's display name.
+pub fn user_name_rules() -> Vec<Rule> {
+    vec![Rule::Required, Rule::MinLength(3), Rule::MaxLength(32)]
+}
+
+/// The rules for an email address the user types in.
+pub fn email_rules() -> Vec<Rule> {
+    vec![Rule::Required, Rule::Email, Rule::MaxLength(254)]
+}
+
+/// The rules every newly chosen password must pass.
+pub fn new_password_rules() -> Vec<Rule> {
+    vec![
+        Rule::Required,
+        Rule::MinLength(8),
+        Rule::MaxLength(128),
+        Rule::PasswordStrength,
+    ]
+}
+
+/// True when every field passes its rules.
+pub fn all_valid(fields: &[Field]) -> bool {
+    fields.iter().all(Field::is_valid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_rejects_blank_values() {
+        assert!(Rule::Required.check("  ").is_err());
+        assert!(Rule::Required.check("x").is_ok());
+    }
+
+    #[test]
+    fn email_needs_local_part_and_dotted_domain() {
+        for valid in ["a@b.co", "first.last@mail.example.com"] {
+            assert!(Rule::Email.check(valid).is_ok(), "{}", valid);
+        }
+        for invalid in ["a", "@b.co", "a@b", "a@b.", "a@@b.co", "a b@c.de", "a@.co"] {
+            assert!(Rule::Email.check(invalid).is_err(), "{}", invalid);
+        }
+        // Left to `Required`.
+        assert!(Rule::Email.check("").is_ok());
+    }
+
+    #[test]
+    fn lengths_count_characters_not_bytes() {
+        assert!(Rule::MinLength(3).check("한글").is_err());
+        assert!(Rule::MaxLength(2).check("한글").is_ok());
+        assert!(Rule::MaxLength(1).check("한글").is_err());
+    }
+
+    #[test]
+    fn password_strength_needs_three_classes() {
+        assert!(Rule::PasswordStrength.check("lowercase1").is_err());
+        assert!(Rule::PasswordStrength.check("Lowercase1").is_ok());
+        assert_eq!(character_classes("aA1!"), 4);
+    }
+
+    #[test]
+    fn slugs_are_lowercase_with_single_dashes() {
+        assert!(Rule::Slug.check("hello-world-2").is_ok());
+        for invalid in [
+            "Hello",
+            "-hello",
+            "hello-",
+            "hello--world",
+            "hello world",
+            "new",
+        ] {
+            assert!(Rule::Slug.check(invalid).is_err(), "{}", invalid);
+        }
+    }
+}